@@ -1,26 +1,35 @@
+use std::collections::BTreeMap;
+
+use ergo_runtime::common::Value;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
 use crate::{EventId, EventPayload, EventTime, ExternalEvent, ExternalEventKind};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExternalEventRecord {
     pub event_id: EventId,
     pub event_time: EventTime,
     pub kind: ExternalEventKind,
     pub payload: EventPayload,
+    /// Covers the payload and, when one was stamped, the environment.
     pub payload_hash: String,
+    /// Environment state the event was stamped with; every state read in its episode
+    /// resolves against this snapshot.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub environment: BTreeMap<String, Value>,
 }
 
 impl ExternalEventRecord {
     pub fn from_event(event: &ExternalEvent) -> Self {
-        let payload_hash = hash_payload(&event.payload);
+        let payload_hash = hash_event(&event.payload, event.environment());
         Self {
             event_id: event.event_id().clone(),
             event_time: event.at(),
             kind: event.kind(),
             payload: event.payload().clone(),
             payload_hash,
+            environment: event.environment().clone(),
         }
     }
 
@@ -31,10 +40,11 @@ impl ExternalEventRecord {
             self.event_time,
             self.payload.clone(),
         )
+        .with_environment(self.environment.clone())
     }
 
     pub fn validate_hash(&self) -> bool {
-        self.payload_hash == hash_payload(&self.payload)
+        self.payload_hash == hash_event(&self.payload, &self.environment)
     }
 }
 
@@ -44,3 +54,17 @@ pub fn hash_payload(payload: &EventPayload) -> String {
     let digest = hasher.finalize();
     hex::encode(digest)
 }
+
+/// Hash of the payload together with the stamped environment. An empty environment
+/// adds nothing, so events without state reads hash as `hash_payload` does.
+pub fn hash_event(payload: &EventPayload, environment: &BTreeMap<String, Value>) -> String {
+    if environment.is_empty() {
+        return hash_payload(payload);
+    }
+    let environment = serde_json::to_vec(environment).expect("environment serializes");
+    let mut hasher = Sha256::new();
+    hasher.update((payload.data.len() as u64).to_le_bytes());
+    hasher.update(&payload.data);
+    hasher.update(&environment);
+    hex::encode(hasher.finalize())
+}
@@ -0,0 +1,61 @@
+//! Key-value environment state owned by the adapter.
+//!
+//! Graphs read the store through the `state_read_number` and `state_read_bool` sources
+//! and write it through the `state_write` action. When an event arrives,
+//! `RuntimeHandle::intake` stamps it with the stored values of the keys the graph's
+//! sources declare (`ergo_runtime::runtime::environment_keys`); the episode reads only
+//! that snapshot, and the event's capture record keeps it, so replay reads the same
+//! values. Writes are applied when the episode completes and are first seen by the
+//! next event stamped.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use ergo_runtime::common::Value;
+
+use crate::ExternalEvent;
+
+/// Shared handle to the store; clones see the same state.
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentStore {
+    state: Arc<Mutex<BTreeMap<String, Value>>>,
+}
+
+impl EnvironmentStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<Value> {
+        self.lock().get(key).cloned()
+    }
+
+    /// Sets a value from outside the graph, such as state restored at startup.
+    pub fn set(&self, key: impl Into<String>, value: Value) {
+        self.lock().insert(key.into(), value);
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<String, Value> {
+        self.lock().clone()
+    }
+
+    /// Attaches the current values of `keys` to `event` as the environment its episode
+    /// reads. Keys never written are left out.
+    pub(crate) fn stamp(&self, event: ExternalEvent, keys: &BTreeSet<String>) -> ExternalEvent {
+        let state = self.lock();
+        let environment = keys
+            .iter()
+            .filter_map(|key| Some((key.clone(), state.get(key)?.clone())))
+            .collect();
+        drop(state);
+        event.with_environment(environment)
+    }
+
+    pub(crate) fn apply(&self, writes: BTreeMap<String, Value>) {
+        self.lock().extend(writes);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, Value>> {
+        self.state.lock().expect("environment store poisoned")
+    }
+}
@@ -1,14 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use ergo_runtime::catalog::{CorePrimitiveCatalog, CoreRegistries};
 use ergo_runtime::cluster::ExpandedGraph;
+use ergo_runtime::common::Value;
 use ergo_runtime::runtime::ExecutionContext as RuntimeExecutionContext;
 use ergo_runtime::runtime::Registries;
 use serde::{Deserialize, Serialize};
 
 pub mod capture;
+pub mod environment;
+
+pub use environment::EnvironmentStore;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
@@ -63,20 +67,18 @@ pub enum RunTermination {
 /// ```compile_fail
 /// use ergo_adapter::ExecutionContext;
 /// use ergo_runtime::runtime::ExecutionContext as RuntimeExecutionContext;
-/// use std::collections::HashMap;
 ///
 /// // Constructor is not visible outside ergo-adapter.
-/// let runtime_ctx = RuntimeExecutionContext { trigger_state: HashMap::new() };
+/// let runtime_ctx = RuntimeExecutionContext::default();
 /// let _ctx = ExecutionContext::new(runtime_ctx);
 /// ```
 ///
 /// ```compile_fail
 /// use ergo_adapter::ExecutionContext;
 /// use ergo_runtime::runtime::ExecutionContext as RuntimeExecutionContext;
-/// use std::collections::HashMap;
 ///
 /// // Opaque fields cannot be set directly.
-/// let runtime_ctx = RuntimeExecutionContext { trigger_state: HashMap::new() };
+/// let runtime_ctx = RuntimeExecutionContext::default();
 /// let _ctx = ExecutionContext { inner: runtime_ctx };
 /// ```
 #[derive(Debug, Clone)]
@@ -145,6 +147,8 @@ pub struct ExternalEvent {
     context: ExecutionContext,
     at: EventTime,
     payload: EventPayload,
+    /// Set once the event carries its episode's environment, by intake or rehydration.
+    stamped: bool,
 }
 
 impl ExternalEvent {
@@ -161,13 +165,12 @@ impl ExternalEvent {
             context,
             at,
             payload,
+            stamped: false,
         }
     }
 
     pub fn mechanical_at(event_id: EventId, kind: ExternalEventKind, at: EventTime) -> Self {
-        let runtime_ctx = RuntimeExecutionContext {
-            trigger_state: HashMap::new(),
-        };
+        let runtime_ctx = RuntimeExecutionContext::default();
         let context = ExecutionContext::new(runtime_ctx);
         Self::new(event_id, kind, context, at, EventPayload::default())
     }
@@ -182,8 +185,11 @@ impl ExternalEvent {
         at: EventTime,
         payload: EventPayload,
     ) -> Self {
+        // The payload reaches the graph only through Sources reading the context, so
+        // an event rehydrated from its capture record feeds the run identical data.
         let runtime_ctx = RuntimeExecutionContext {
-            trigger_state: HashMap::new(),
+            payload: payload.data.clone(),
+            ..RuntimeExecutionContext::default()
         };
         let context = ExecutionContext::new(runtime_ctx);
         Self::new(event_id, kind, context, at, payload)
@@ -208,15 +214,35 @@ impl ExternalEvent {
     pub fn payload(&self) -> &EventPayload {
         &self.payload
     }
+
+    /// Environment state the episode for this event reads; empty unless the event went
+    /// through `RuntimeHandle::intake` or was rehydrated from a record that did.
+    pub fn environment(&self) -> &BTreeMap<String, Value> {
+        &self.context.inner.environment
+    }
+
+    pub(crate) fn is_stamped(&self) -> bool {
+        self.stamped
+    }
+
+    pub(crate) fn with_environment(mut self, environment: BTreeMap<String, Value>) -> Self {
+        self.context.inner.environment = environment;
+        self.stamped = true;
+        self
+    }
 }
 
 /// RuntimeHandle holds the execution dependencies needed to invoke the runtime.
 /// It is constructed with an expanded graph, primitive catalog, and registries.
+/// Environment writes from completed runs are applied to its `EnvironmentStore`.
 #[derive(Clone)]
 pub struct RuntimeHandle {
     graph: Arc<ExpandedGraph>,
     catalog: Arc<CorePrimitiveCatalog>,
     registries: Arc<CoreRegistries>,
+    environment: EnvironmentStore,
+    /// Environment keys the graph's state sources read; the only ones stamped.
+    read_keys: Arc<BTreeSet<String>>,
 }
 
 impl RuntimeHandle {
@@ -225,13 +251,32 @@ impl RuntimeHandle {
         catalog: Arc<CorePrimitiveCatalog>,
         registries: Arc<CoreRegistries>,
     ) -> Self {
+        let read_keys = Arc::new(ergo_runtime::runtime::environment_keys(
+            &graph,
+            &registries.sources,
+        ));
         Self {
             graph,
             catalog,
             registries,
+            environment: EnvironmentStore::new(),
+            read_keys,
         }
     }
 
+    pub fn environment(&self) -> &EnvironmentStore {
+        &self.environment
+    }
+
+    /// Stamps a live event with the stored values of the keys the graph reads. Events
+    /// already stamped, such as those rehydrated for replay, are returned unchanged.
+    pub fn intake(&self, event: ExternalEvent) -> ExternalEvent {
+        if event.is_stamped() {
+            return event;
+        }
+        self.environment.stamp(event, &self.read_keys)
+    }
+
     pub fn run(
         &self,
         graph_id: &GraphId,
@@ -255,15 +300,25 @@ impl RuntimeHandle {
         };
 
         // Call runtime::run, consume ExecutionReport internally (SUP-2)
-        match ergo_runtime::runtime::run(&self.graph, &*self.catalog, &registries, ctx.inner())
-        {
-            Ok(_report) => RunTermination::Completed,
+        match ergo_runtime::runtime::run(&self.graph, &*self.catalog, &registries, ctx.inner()) {
+            Ok(report) => {
+                self.environment.apply(report.environment_writes);
+                RunTermination::Completed
+            }
+            // Any runtime error, including a caught primitive panic, is an
+            // ordinary mechanical failure; it never unwinds into the supervisor.
             Err(_) => RunTermination::Failed(ErrKind::RuntimeError),
         }
     }
 }
 
 pub trait RuntimeInvoker {
+    /// Called on every event before it is captured or run, to attach the adapter state
+    /// the episode will read. The default attaches nothing.
+    fn intake(&self, event: ExternalEvent) -> ExternalEvent {
+        event
+    }
+
     fn run(
         &self,
         graph_id: &GraphId,
@@ -274,6 +329,10 @@ pub trait RuntimeInvoker {
 }
 
 impl RuntimeInvoker for RuntimeHandle {
+    fn intake(&self, event: ExternalEvent) -> ExternalEvent {
+        Self::intake(self, event)
+    }
+
     fn run(
         &self,
         graph_id: &GraphId,
@@ -355,9 +414,7 @@ mod tests {
     fn fault_runtime_handle_aborts_when_deadline_zero() {
         // FaultRuntimeHandle (the test double) should respect deadline=zero
         let handle = FaultRuntimeHandle::new(RunTermination::Completed);
-        let rt_ctx = ergo_runtime::runtime::ExecutionContext {
-            trigger_state: HashMap::new(),
-        };
+        let rt_ctx = ergo_runtime::runtime::ExecutionContext::default();
         let ctx = ExecutionContext::new(rt_ctx);
         let term = handle.run(
             &GraphId::new("g"),
@@ -377,9 +434,7 @@ mod tests {
             vec![RunTermination::Failed(ErrKind::NetworkTimeout)],
         );
 
-        let rt_ctx = ergo_runtime::runtime::ExecutionContext {
-            trigger_state: HashMap::new(),
-        };
+        let rt_ctx = ergo_runtime::runtime::ExecutionContext::default();
         let ctx = ExecutionContext::new(rt_ctx);
 
         // First call returns scheduled outcome
@@ -390,4 +445,260 @@ mod tests {
         let term = handle.run(&GraphId::new("g"), &EventId::new("e1"), &ctx, None);
         assert_eq!(term, RunTermination::Completed);
     }
+
+    struct PanickingNumberSource {
+        manifest: ergo_runtime::source::SourcePrimitiveManifest,
+    }
+
+    impl ergo_runtime::source::SourcePrimitive for PanickingNumberSource {
+        fn manifest(&self) -> &ergo_runtime::source::SourcePrimitiveManifest {
+            &self.manifest
+        }
+
+        fn produce(
+            &self,
+            _parameters: &HashMap<String, ergo_runtime::source::ParameterValue>,
+        ) -> HashMap<String, ergo_runtime::common::Value> {
+            panic!("feed handler crashed")
+        }
+    }
+
+    #[test]
+    fn runtime_handle_maps_primitive_panic_to_failed() {
+        use ergo_runtime::catalog::build_core_catalog;
+        use ergo_runtime::cluster::{
+            ExpandedNode, ImplementationInstance, OutputPortSpec, OutputRef,
+        };
+
+        let graph = ExpandedGraph {
+            nodes: HashMap::from([(
+                "src".to_string(),
+                ExpandedNode {
+                    runtime_id: "src".to_string(),
+                    authoring_path: vec![],
+                    implementation: ImplementationInstance {
+                        impl_id: "number_source".to_string(),
+                        version: "0.1.0".to_string(),
+                    },
+                    parameters: HashMap::new(),
+                },
+            )]),
+            edges: Vec::new(),
+            boundary_inputs: Vec::new(),
+            boundary_outputs: vec![OutputPortSpec {
+                name: "value".to_string(),
+                maps_to: OutputRef {
+                    node_id: "src".to_string(),
+                    port_name: "value".to_string(),
+                },
+            }],
+        };
+
+        let mut sources = ergo_runtime::source::SourceRegistry::new();
+        sources
+            .register(Box::new(PanickingNumberSource {
+                manifest: ergo_runtime::source::implementations::number_source_manifest(),
+            }))
+            .unwrap();
+        let registries = CoreRegistries::new(
+            sources,
+            ergo_runtime::compute::PrimitiveRegistry::new(),
+            ergo_runtime::trigger::TriggerRegistry::new(),
+            ergo_runtime::action::ActionRegistry::new(),
+        );
+        let handle = RuntimeHandle::new(
+            Arc::new(graph),
+            Arc::new(build_core_catalog()),
+            Arc::new(registries),
+        );
+
+        let event = ExternalEvent::mechanical(EventId::new("e1"), ExternalEventKind::Tick);
+        let term = handle.run(&GraphId::new("g"), event.event_id(), event.context(), None);
+        assert_eq!(term, RunTermination::Failed(ErrKind::RuntimeError));
+    }
+
+    #[test]
+    fn payload_source_reads_the_same_value_after_capture_and_rehydration() {
+        use ergo_runtime::catalog::{build_core_catalog, core_registries};
+        use ergo_runtime::cluster::{
+            ExpandedNode, ImplementationInstance, OutputPortSpec, OutputRef, ParameterValue,
+        };
+        use ergo_runtime::runtime::RuntimeValue;
+
+        let graph = ExpandedGraph {
+            nodes: HashMap::from([(
+                "bid".to_string(),
+                ExpandedNode {
+                    runtime_id: "bid".to_string(),
+                    authoring_path: vec![],
+                    implementation: ImplementationInstance {
+                        impl_id: "payload_number".to_string(),
+                        version: "0.1.0".to_string(),
+                    },
+                    parameters: HashMap::from([(
+                        "path".to_string(),
+                        ParameterValue::String("/quote/bid".to_string()),
+                    )]),
+                },
+            )]),
+            edges: Vec::new(),
+            boundary_inputs: Vec::new(),
+            boundary_outputs: vec![OutputPortSpec {
+                name: "bid".to_string(),
+                maps_to: OutputRef {
+                    node_id: "bid".to_string(),
+                    port_name: "value".to_string(),
+                },
+            }],
+        };
+        let catalog = build_core_catalog();
+        let core = core_registries().unwrap();
+        let registries = Registries {
+            sources: &core.sources,
+            computes: &core.computes,
+            triggers: &core.triggers,
+            actions: &core.actions,
+        };
+
+        let event = ExternalEvent::with_payload(
+            EventId::new("e1"),
+            ExternalEventKind::DataAvailable,
+            EventTime::default(),
+            EventPayload {
+                data: br#"{"quote": {"bid": 101.5}}"#.to_vec(),
+            },
+        );
+        let replayed = capture::ExternalEventRecord::from_event(&event).rehydrate();
+
+        for event in [&event, &replayed] {
+            let report =
+                ergo_runtime::runtime::run(&graph, &catalog, &registries, event.context().inner())
+                    .unwrap();
+            assert_eq!(report.outputs["bid"], RuntimeValue::Number(101.5));
+        }
+    }
+
+    #[test]
+    fn environment_writes_reach_the_next_event_and_replay_reads_the_capture() {
+        use ergo_runtime::catalog::{build_core_catalog, core_registries};
+        use ergo_runtime::cluster::{
+            ExpandedEdge, ExpandedEndpoint, ExpandedNode, ImplementationInstance, OutputPortSpec,
+            OutputRef, ParameterValue,
+        };
+        use ergo_runtime::runtime::RuntimeValue;
+
+        // Latch: the first event finds `armed` unset and sets it; later events see it set.
+        let node = |id: &str, impl_id: &str, parameters: Vec<(&str, ParameterValue)>| {
+            let node = ExpandedNode {
+                runtime_id: id.to_string(),
+                authoring_path: vec![],
+                implementation: ImplementationInstance {
+                    impl_id: impl_id.to_string(),
+                    version: "0.1.0".to_string(),
+                },
+                parameters: parameters
+                    .into_iter()
+                    .map(|(name, value)| (name.to_string(), value))
+                    .collect(),
+            };
+            (id.to_string(), node)
+        };
+        let edge = |from: &str, output: &str, to: &str, input: &str| ExpandedEdge {
+            from: ExpandedEndpoint::NodePort {
+                node_id: from.to_string(),
+                port_name: output.to_string(),
+            },
+            to: ExpandedEndpoint::NodePort {
+                node_id: to.to_string(),
+                port_name: input.to_string(),
+            },
+        };
+        let graph = ExpandedGraph {
+            nodes: HashMap::from([
+                node(
+                    "armed",
+                    "state_read_bool",
+                    vec![("key", ParameterValue::String("armed".to_string()))],
+                ),
+                node("unarmed", "not", vec![]),
+                node("arm", "emit_if_true", vec![]),
+                node(
+                    "write",
+                    "state_write",
+                    vec![
+                        ("key", ParameterValue::String("armed".to_string())),
+                        ("value_type", ParameterValue::Enum("bool".to_string())),
+                        ("flag", ParameterValue::Bool(true)),
+                    ],
+                ),
+            ]),
+            edges: vec![
+                edge("armed", "value", "unarmed", "value"),
+                edge("unarmed", "result", "arm", "input"),
+                edge("arm", "event", "write", "event"),
+            ],
+            boundary_inputs: Vec::new(),
+            boundary_outputs: vec![OutputPortSpec {
+                name: "armed".to_string(),
+                maps_to: OutputRef {
+                    node_id: "armed".to_string(),
+                    port_name: "value".to_string(),
+                },
+            }],
+        };
+        let catalog = build_core_catalog();
+        let core = core_registries().unwrap();
+        let handle = RuntimeHandle::new(
+            Arc::new(graph.clone()),
+            Arc::new(build_core_catalog()),
+            Arc::new(core_registries().unwrap()),
+        );
+        let store = handle.environment();
+        store.set("unread", Value::Number(1.0));
+
+        let first = handle.intake(ExternalEvent::mechanical(
+            EventId::new("e1"),
+            ExternalEventKind::Tick,
+        ));
+        let term = handle.run(&GraphId::new("g"), first.event_id(), first.context(), None);
+        assert_eq!(term, RunTermination::Completed);
+        assert_eq!(store.get("armed"), Some(Value::Bool(true)));
+        assert!(first.environment().is_empty());
+
+        let second = handle.intake(ExternalEvent::mechanical(
+            EventId::new("e2"),
+            ExternalEventKind::Tick,
+        ));
+        // Only the key the graph reads is captured, and the hash covers it.
+        assert_eq!(
+            second.environment(),
+            &BTreeMap::from([("armed".to_string(), Value::Bool(true))])
+        );
+        let mut record = capture::ExternalEventRecord::from_event(&second);
+        assert!(record.validate_hash());
+        record
+            .environment
+            .insert("armed".to_string(), Value::Bool(false));
+        assert!(!record.validate_hash());
+
+        let replayed = capture::ExternalEventRecord::from_event(&first).rehydrate();
+        let restamped = handle.intake(replayed.clone());
+        assert!(restamped.environment().is_empty());
+
+        let registries = Registries {
+            sources: &core.sources,
+            computes: &core.computes,
+            triggers: &core.triggers,
+            actions: &core.actions,
+        };
+        let armed = |event: &ExternalEvent| {
+            ergo_runtime::runtime::run(&graph, &catalog, &registries, event.context().inner())
+                .unwrap()
+                .outputs["armed"]
+                .clone()
+        };
+        assert_eq!(armed(&first), RuntimeValue::Bool(false));
+        assert_eq!(armed(&replayed), RuntimeValue::Bool(false));
+        assert_eq!(armed(&second), RuntimeValue::Bool(true));
+    }
 }
@@ -23,6 +23,7 @@ pub fn ack_action_manifest() -> ActionPrimitiveManifest {
             value_type: ParameterValue::Bool(true).value_type(),
             default: Some(ParameterValue::Bool(true)),
             bounds: None,
+            variants: Vec::new(),
         }],
         execution: ExecutionSpec {
             deterministic: true,
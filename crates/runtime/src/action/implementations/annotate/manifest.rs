@@ -23,6 +23,7 @@ pub fn annotate_action_manifest() -> ActionPrimitiveManifest {
             value_type: ParameterValue::String(String::new()).value_type(),
             default: Some(ParameterValue::String(String::new())),
             bounds: None,
+            variants: Vec::new(),
         }],
         execution: ExecutionSpec {
             deterministic: true,
@@ -1,5 +1,7 @@
 pub mod ack;
 pub mod annotate;
+pub mod state_write;
 
 pub use ack::{ack_action_manifest, AckAction};
 pub use annotate::{annotate_action_manifest, AnnotateAction};
+pub use state_write::{state_write_action_manifest, StateWriteAction};
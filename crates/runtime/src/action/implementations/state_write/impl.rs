@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use crate::action::{
+    ActionContext, ActionError, ActionOutcome, ActionPrimitiveManifest, ActionValue,
+    FallibleActionPrimitive, ParameterValue,
+};
+use crate::common::Value;
+
+use super::manifest::state_write_action_manifest;
+
+/// Writes `number` or `flag`, as `value_type` selects, to the environment under `key`.
+/// The write is handed back to the host with the pass's report; `state_read_number`
+/// and `state_read_bool` see it from the next episode on.
+pub struct StateWriteAction {
+    manifest: ActionPrimitiveManifest,
+}
+
+impl StateWriteAction {
+    pub fn new() -> Self {
+        Self {
+            manifest: state_write_action_manifest(),
+        }
+    }
+}
+
+impl Default for StateWriteAction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleActionPrimitive for StateWriteAction {
+    fn manifest(&self) -> &ActionPrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_execute(
+        &self,
+        inputs: &HashMap<String, ActionValue>,
+        parameters: &HashMap<String, ParameterValue>,
+        ctx: &mut ActionContext,
+    ) -> Result<HashMap<String, ActionValue>, ActionError> {
+        inputs
+            .get("event")
+            .and_then(|v| v.as_event())
+            .ok_or_else(|| ActionError::MissingInput {
+                name: "event".to_string(),
+            })?;
+
+        let invalid = |name: &str, reason: &str| ActionError::InvalidParameter {
+            name: name.to_string(),
+            reason: reason.to_string(),
+        };
+        let key = match parameters.get("key") {
+            Some(ParameterValue::String(key)) => key.clone(),
+            _ => return Err(invalid("key", "expected a string")),
+        };
+        let value = match parameters.get("value_type") {
+            Some(ParameterValue::Enum(t)) if t == "number" => match parameters.get("number") {
+                Some(ParameterValue::Number(n)) => Value::Number(*n),
+                _ => return Err(invalid("number", "expected a number")),
+            },
+            Some(ParameterValue::Enum(t)) if t == "bool" => match parameters.get("flag") {
+                Some(ParameterValue::Bool(b)) => Value::Bool(*b),
+                _ => return Err(invalid("flag", "expected a bool")),
+            },
+            _ => return Err(invalid("value_type", "expected number or bool")),
+        };
+        ctx.environment_writes.insert(key, value);
+
+        Ok(HashMap::from([(
+            "outcome".to_string(),
+            ActionValue::Event(ActionOutcome::Attempted),
+        )]))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::action::{
+    ActionKind, ActionPrimitiveManifest, ActionValueType, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, ParameterValue, StateSpec,
+};
+
+pub fn state_write_action_manifest() -> ActionPrimitiveManifest {
+    ActionPrimitiveManifest {
+        id: "state_write".to_string(),
+        version: "0.1.0".to_string(),
+        kind: ActionKind::Action,
+        inputs: vec![InputSpec {
+            name: "event".to_string(),
+            value_type: ActionValueType::Event,
+            required: true,
+            cardinality: crate::action::Cardinality::Single,
+        }],
+        outputs: vec![OutputSpec {
+            name: "outcome".to_string(),
+            value_type: ActionValueType::Event,
+        }],
+        parameters: vec![
+            ParameterSpec {
+                name: "key".to_string(),
+                value_type: ParameterType::String,
+                default: None,
+                bounds: Some("len[1, )".to_string()),
+                variants: Vec::new(),
+            },
+            // Which of `number` and `flag` is written.
+            ParameterSpec {
+                name: "value_type".to_string(),
+                value_type: ParameterType::Enum,
+                default: Some(ParameterValue::Enum("number".to_string())),
+                bounds: None,
+                variants: vec!["number".to_string(), "bool".to_string()],
+            },
+            ParameterSpec {
+                name: "number".to_string(),
+                value_type: ParameterType::Number,
+                default: Some(ParameterValue::Number(0.0)),
+                bounds: None,
+                variants: Vec::new(),
+            },
+            ParameterSpec {
+                name: "flag".to_string(),
+                value_type: ParameterType::Bool,
+                default: Some(ParameterValue::Bool(false)),
+                bounds: None,
+                variants: Vec::new(),
+            },
+        ],
+        execution: ExecutionSpec {
+            deterministic: true,
+            retryable: false,
+        },
+        state: StateSpec { allowed: false },
+        side_effects: true,
+    }
+}
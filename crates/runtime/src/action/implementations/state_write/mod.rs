@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::state_write_action_manifest;
+pub use r#impl::StateWriteAction;
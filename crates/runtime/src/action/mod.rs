@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::common::parameter::ParameterDeclarationError;
+use crate::common::Value;
 
 pub mod graph;
 pub mod implementations;
@@ -18,6 +21,7 @@ pub enum ActionValueType {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ActionOutcome {
     Attempted,
     Filled,
@@ -54,36 +58,6 @@ impl ActionValue {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum ParameterType {
-    Int,
-    Number,
-    Bool,
-    String,
-    Enum,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum ParameterValue {
-    Int(i64),
-    Number(f64),
-    Bool(bool),
-    String(String),
-    Enum(String),
-}
-
-impl ParameterValue {
-    pub fn value_type(&self) -> ParameterType {
-        match self {
-            ParameterValue::Int(_) => ParameterType::Int,
-            ParameterValue::Number(_) => ParameterType::Number,
-            ParameterValue::Bool(_) => ParameterType::Bool,
-            ParameterValue::String(_) => ParameterType::String,
-            ParameterValue::Enum(_) => ParameterType::Enum,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq)]
 pub enum Cardinality {
     Single,
@@ -103,14 +77,6 @@ pub struct OutputSpec {
     pub value_type: ActionValueType,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct ParameterSpec {
-    pub name: String,
-    pub value_type: ParameterType,
-    pub default: Option<ParameterValue>,
-    pub bounds: Option<String>,
-}
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExecutionSpec {
     pub deterministic: bool,
@@ -184,6 +150,10 @@ pub enum ActionValidationError {
         expected: ParameterType,
         got: ParameterType,
     },
+    InvalidParameterDeclaration {
+        parameter: String,
+        error: ParameterDeclarationError,
+    },
     UnknownPrimitive(String),
     CycleDetected,
     MissingNode(String),
@@ -207,8 +177,58 @@ pub trait ActionPrimitive {
     ) -> HashMap<String, ActionValue>;
 }
 
+/// Failure reported by an action primitive in place of a panic.
+///
+/// An `ActionError` aborts the pass. An attempt that was made and failed in the
+/// external environment can still be reported as data via `ActionOutcome::Failed`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionError {
+    MissingInput { name: String },
+    InvalidInput { name: String, reason: String },
+    InvalidParameter { name: String, reason: String },
+    EffectFailed(String),
+}
+
+/// Effects an action hands back to the host besides its outputs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActionContext {
+    /// Environment state to write, keyed by name. The runtime reports the writes in
+    /// `ExecutionReport::environment_writes`; no read in the same pass observes them.
+    pub environment_writes: BTreeMap<String, Value>,
+}
+
+/// Result-returning form of `ActionPrimitive`. This is what the registry stores
+/// and the runtime calls; every `ActionPrimitive` gets it through the blanket impl.
+/// Actions that write environment state implement it directly.
+pub trait FallibleActionPrimitive {
+    fn manifest(&self) -> &ActionPrimitiveManifest;
+
+    fn try_execute(
+        &self,
+        inputs: &HashMap<String, ActionValue>,
+        parameters: &HashMap<String, ParameterValue>,
+        ctx: &mut ActionContext,
+    ) -> Result<HashMap<String, ActionValue>, ActionError>;
+}
+
+impl<T: ActionPrimitive> FallibleActionPrimitive for T {
+    fn manifest(&self) -> &ActionPrimitiveManifest {
+        ActionPrimitive::manifest(self)
+    }
+
+    fn try_execute(
+        &self,
+        inputs: &HashMap<String, ActionValue>,
+        parameters: &HashMap<String, ParameterValue>,
+        _ctx: &mut ActionContext,
+    ) -> Result<HashMap<String, ActionValue>, ActionError> {
+        Ok(self.execute(inputs, parameters))
+    }
+}
+
+pub use crate::common::parameter::{ParameterSpec, ParameterType, ParameterValue};
 pub use graph::{ActionGraph, ActionNode, InputBinding, NodeOutputRef};
-pub use implementations::{AckAction, AnnotateAction};
+pub use implementations::{AckAction, AnnotateAction, StateWriteAction};
 pub use registry::ActionRegistry;
 
 #[cfg(test)]
@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 
 use super::{
-    ActionKind, ActionPrimitive, ActionPrimitiveManifest, ActionValidationError, ActionValueType,
-    OutputSpec,
+    ActionKind, ActionPrimitiveManifest, ActionValidationError, ActionValueType,
+    FallibleActionPrimitive, OutputSpec, ParameterSpec,
 };
 
 pub struct ActionRegistry {
-    primitives: HashMap<String, Box<dyn ActionPrimitive>>,
+    /// Keyed by (id, version) so several versions of a primitive can coexist.
+    primitives: HashMap<(String, String), Box<dyn FallibleActionPrimitive>>,
 }
 
 impl ActionRegistry {
@@ -51,10 +52,24 @@ impl ActionRegistry {
         }
 
         Self::validate_outputs(&manifest.outputs)?;
+        Self::validate_parameters(&manifest.parameters)?;
 
         Ok(())
     }
 
+    /// Each declaration must parse and pass `common::parameter::check_declaration`.
+    fn validate_parameters(parameters: &[ParameterSpec]) -> Result<(), ActionValidationError> {
+        for param in parameters {
+            param.parsed_bounds().map_err(|error| {
+                ActionValidationError::InvalidParameterDeclaration {
+                    parameter: param.name.clone(),
+                    error,
+                }
+            })?;
+        }
+        Ok(())
+    }
+
     fn validate_outputs(outputs: &[OutputSpec]) -> Result<(), ActionValidationError> {
         if outputs.len() != 1 {
             return Err(ActionValidationError::UndeclaredOutput {
@@ -77,22 +92,26 @@ impl ActionRegistry {
 
     pub fn register(
         &mut self,
-        primitive: Box<dyn ActionPrimitive>,
+        primitive: Box<dyn FallibleActionPrimitive>,
     ) -> Result<(), ActionValidationError> {
         let manifest = primitive.manifest();
 
         Self::validate_manifest(manifest)?;
 
-        if self.primitives.contains_key(&manifest.id) {
+        let key = (manifest.id.clone(), manifest.version.clone());
+        if self.primitives.contains_key(&key) {
             return Err(ActionValidationError::DuplicateId(manifest.id.clone()));
         }
 
-        self.primitives.insert(manifest.id.clone(), primitive);
+        self.primitives.insert(key, primitive);
         Ok(())
     }
 
-    pub fn get(&self, id: &str) -> Option<&Box<dyn ActionPrimitive>> {
-        self.primitives.get(id)
+    /// Exact lookup; there is no fallback to another version of the same id.
+    pub fn get(&self, id: &str, version: &str) -> Option<&dyn FallibleActionPrimitive> {
+        self.primitives
+            .get(&(id.to_string(), version.to_string()))
+            .map(|p| p.as_ref())
     }
 }
 
@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::action::{
-    AckAction, ActionOutcome, ActionPrimitive, ActionValue, AnnotateAction, ParameterValue,
+    AckAction, ActionContext, ActionError, ActionOutcome, ActionPrimitive, ActionValue,
+    AnnotateAction, FallibleActionPrimitive, ParameterValue, StateWriteAction,
 };
+use crate::common::Value;
 
 fn expect_panic<F: FnOnce() -> R + std::panic::UnwindSafe, R>(f: F) {
     assert!(std::panic::catch_unwind(f).is_err());
@@ -55,6 +57,56 @@ fn annotate_action_emits_attempted() {
     );
 }
 
+#[test]
+fn state_write_records_the_selected_value_under_its_key() {
+    let action = StateWriteAction::new();
+    let event = HashMap::from([(
+        "event".to_string(),
+        ActionValue::Event(ActionOutcome::Attempted),
+    )]);
+    let parameters = |value_type: &str| {
+        HashMap::from([
+            (
+                "key".to_string(),
+                ParameterValue::String("count".to_string()),
+            ),
+            (
+                "value_type".to_string(),
+                ParameterValue::Enum(value_type.to_string()),
+            ),
+            ("number".to_string(), ParameterValue::Number(3.0)),
+            ("flag".to_string(), ParameterValue::Bool(true)),
+        ])
+    };
+
+    let mut ctx = ActionContext::default();
+    let outputs = action
+        .try_execute(&event, &parameters("number"), &mut ctx)
+        .unwrap();
+    assert_eq!(
+        outputs.get("outcome"),
+        Some(&ActionValue::Event(ActionOutcome::Attempted))
+    );
+    assert_eq!(
+        ctx.environment_writes,
+        BTreeMap::from([("count".to_string(), Value::Number(3.0))])
+    );
+
+    action
+        .try_execute(&event, &parameters("bool"), &mut ctx)
+        .unwrap();
+    assert_eq!(ctx.environment_writes["count"], Value::Bool(true));
+
+    assert!(matches!(
+        action.try_execute(&event, &parameters("text"), &mut ctx),
+        Err(ActionError::InvalidParameter { name, .. }) if name == "value_type"
+    ));
+    assert!(matches!(
+        action.try_execute(&HashMap::new(), &parameters("number"), &mut ctx),
+        Err(ActionError::MissingInput { name }) if name == "event"
+    ));
+}
+
 #[test]
 fn actions_require_event_input() {
     let action = AckAction::new();
@@ -1,31 +1,58 @@
 use std::collections::HashMap;
 
 use crate::action::{
-    implementations::{ack_action_manifest, annotate_action_manifest},
+    implementations::{ack_action_manifest, annotate_action_manifest, state_write_action_manifest},
     AckAction, ActionRegistry, ActionValidationError, ActionValueType, AnnotateAction,
+    StateWriteAction,
 };
 use crate::cluster::{
-    Cardinality, InputMetadata, OutputMetadata, PrimitiveCatalog, PrimitiveKind, PrimitiveMetadata,
-    ValueType, Version,
+    Cardinality, InputMetadata, OutputMetadata, ParameterSpec, PrimitiveCatalog, PrimitiveKind,
+    PrimitiveMetadata, ValueType, Version,
 };
 use crate::common;
+use crate::common::parameter::ParameterDeclarationError;
 use crate::common::ValidationError;
 use crate::compute::implementations::{
-    add::add_manifest, and::and_manifest, const_bool::const_bool_manifest,
-    const_number::const_number_manifest, divide::divide_manifest, eq::eq_manifest, gt::gt_manifest,
-    lt::lt_manifest, multiply::multiply_manifest, negate::negate_manifest, neq::neq_manifest,
-    not::not_manifest, or::or_manifest, select::select_manifest, subtract::subtract_manifest, Add,
-    And, ConstBool, ConstNumber, Divide, Eq, Gt, Lt, Multiply, Negate, Neq, Not, Or, Select,
-    Subtract,
+    abs::abs_manifest, add::add_manifest, and::and_manifest, approx_eq::approx_eq_manifest,
+    at::at_manifest, between::between_manifest, ceil::ceil_manifest, clamp::clamp_manifest,
+    concat::concat_manifest, const_bool::const_bool_manifest, const_number::const_number_manifest,
+    cumsum::cumsum_manifest, diff::diff_manifest, divide::divide_manifest, eq::eq_manifest,
+    exp::exp_manifest, floor::floor_manifest, format::format_manifest, gt::gt_manifest,
+    gte::gte_manifest, implies::implies_manifest, lag::lag_manifest, last::last_manifest,
+    len::len_manifest, ln::ln_manifest, lt::lt_manifest, lte::lte_manifest, max::max_manifest,
+    min::min_manifest, modulo::modulo_manifest, multiply::multiply_manifest, nand::nand_manifest,
+    negate::negate_manifest, neq::neq_manifest, not::not_manifest, or::or_manifest,
+    pow::pow_manifest, rolling_max::rolling_max_manifest, rolling_mean::rolling_mean_manifest,
+    rolling_min::rolling_min_manifest, rolling_stdev::rolling_stdev_manifest,
+    rolling_sum::rolling_sum_manifest, round::round_manifest, safe_divide::safe_divide_manifest,
+    select::select_manifest, sign::sign_manifest, slice::slice_manifest, sqrt::sqrt_manifest,
+    str_contains::str_contains_manifest, str_eq::str_eq_manifest, subtract::subtract_manifest,
+    to_string::to_string_manifest, xor::xor_manifest, Abs, Add, And, ApproxEq, At, Between, Ceil,
+    Clamp, Concat, ConstBool, ConstNumber, Cumsum, Diff, Divide, Eq, Exp, Floor, Format, Gt, Gte,
+    Implies, Lag, Last, Len, Ln, Lt, Lte, Max, Min, Modulo, Multiply, Nand, Negate, Neq, Not,
+    NumberToString, Or, Pow, RollingMax, RollingMean, RollingMin, RollingStdev, RollingSum, Round,
+    SafeDivide, Select, Sign, Slice, Sqrt, StrContains, StrEq, Subtract, Xor,
 };
 use crate::compute::{ComputePrimitiveManifest, PrimitiveRegistry as ComputeRegistry};
+#[cfg(feature = "serde")]
+use crate::source::{implementations::payload_number_source_manifest, PayloadNumberSource};
 use crate::source::{
-    implementations::{boolean_source_manifest, number_source_manifest},
-    BooleanSource, NumberSource, SourceRegistry, SourceValidationError,
+    implementations::{
+        boolean_source_manifest, number_source_manifest, state_read_bool_source_manifest,
+        state_read_number_source_manifest, string_source_manifest,
+    },
+    BooleanSource, NumberSource, SourceRegistry, SourceValidationError, StateReadBoolSource,
+    StateReadNumberSource, StringSource,
 };
 use crate::trigger::{
-    implementations::emit_if_true::emit_if_true_manifest, EmitIfTrue, TriggerRegistry,
-    TriggerValidationError, TriggerValueType,
+    implementations::{
+        emit_if_above::emit_if_above_manifest, emit_if_all::emit_if_all_manifest,
+        emit_if_any::emit_if_any_manifest, emit_if_below::emit_if_below_manifest,
+        emit_if_changed_from::emit_if_changed_from_manifest, emit_if_false::emit_if_false_manifest,
+        emit_if_true::emit_if_true_manifest,
+    },
+    EmitIfAbove, EmitIfAll, EmitIfAny, EmitIfBelow, EmitIfChangedFrom, EmitIfFalse, EmitIfTrue,
+    TriggerRegistry, TriggerValidationError, TriggerValueType,
 };
 
 #[derive(Debug)]
@@ -36,6 +63,16 @@ pub enum CoreRegistrationError {
     Action(ActionValidationError),
 }
 
+/// A manifest the catalog cannot describe.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogError {
+    InvalidParameterDeclaration {
+        primitive: String,
+        parameter: String,
+        error: ParameterDeclarationError,
+    },
+}
+
 pub struct CoreRegistries {
     pub sources: SourceRegistry,
     pub computes: ComputeRegistry,
@@ -67,6 +104,20 @@ pub fn core_registries() -> Result<CoreRegistries, CoreRegistrationError> {
     sources
         .register(Box::new(BooleanSource::new()))
         .map_err(CoreRegistrationError::Source)?;
+    // Parsing the JSON payload needs serde_json, which comes with the `serde` feature.
+    #[cfg(feature = "serde")]
+    sources
+        .register(Box::new(PayloadNumberSource::new()))
+        .map_err(CoreRegistrationError::Source)?;
+    sources
+        .register(Box::new(StringSource::new()))
+        .map_err(CoreRegistrationError::Source)?;
+    sources
+        .register(Box::new(StateReadNumberSource::new()))
+        .map_err(CoreRegistrationError::Source)?;
+    sources
+        .register(Box::new(StateReadBoolSource::new()))
+        .map_err(CoreRegistrationError::Source)?;
 
     let mut computes = ComputeRegistry::new();
     computes
@@ -87,6 +138,9 @@ pub fn core_registries() -> Result<CoreRegistries, CoreRegistrationError> {
     computes
         .register(Box::new(Divide::new()))
         .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(SafeDivide::new()))
+        .map_err(CoreRegistrationError::Compute)?;
     computes
         .register(Box::new(Negate::new()))
         .map_err(CoreRegistrationError::Compute)?;
@@ -114,11 +168,140 @@ pub fn core_registries() -> Result<CoreRegistries, CoreRegistrationError> {
     computes
         .register(Box::new(Select::new()))
         .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Gte::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Lte::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Between::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(ApproxEq::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Xor::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Nand::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Implies::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Len::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Last::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(At::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Slice::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Lag::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(RollingMean::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(RollingSum::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(RollingMin::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(RollingMax::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(RollingStdev::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Cumsum::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Diff::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Abs::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Min::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Max::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Clamp::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Pow::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Sqrt::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Ln::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Exp::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Round::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Floor::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Ceil::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Modulo::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Sign::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Concat::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(Format::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(StrEq::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(StrContains::new()))
+        .map_err(CoreRegistrationError::Compute)?;
+    computes
+        .register(Box::new(NumberToString::new()))
+        .map_err(CoreRegistrationError::Compute)?;
 
     let mut triggers = TriggerRegistry::new();
     triggers
         .register(Box::new(EmitIfTrue::new()))
         .map_err(CoreRegistrationError::Trigger)?;
+    triggers
+        .register(Box::new(EmitIfFalse::new()))
+        .map_err(CoreRegistrationError::Trigger)?;
+    triggers
+        .register(Box::new(EmitIfAll::new()))
+        .map_err(CoreRegistrationError::Trigger)?;
+    triggers
+        .register(Box::new(EmitIfAny::new()))
+        .map_err(CoreRegistrationError::Trigger)?;
+    triggers
+        .register(Box::new(EmitIfAbove::new()))
+        .map_err(CoreRegistrationError::Trigger)?;
+    triggers
+        .register(Box::new(EmitIfBelow::new()))
+        .map_err(CoreRegistrationError::Trigger)?;
+    triggers
+        .register(Box::new(EmitIfChangedFrom::new()))
+        .map_err(CoreRegistrationError::Trigger)?;
 
     let mut actions = ActionRegistry::new();
     actions
@@ -127,6 +310,9 @@ pub fn core_registries() -> Result<CoreRegistries, CoreRegistrationError> {
     actions
         .register(Box::new(AnnotateAction::new()))
         .map_err(CoreRegistrationError::Action)?;
+    actions
+        .register(Box::new(StateWriteAction::new()))
+        .map_err(CoreRegistrationError::Action)?;
 
     Ok(CoreRegistries::new(sources, computes, triggers, actions))
 }
@@ -142,7 +328,10 @@ impl CorePrimitiveCatalog {
         }
     }
 
-    pub fn register_compute(&mut self, manifest: ComputePrimitiveManifest) {
+    pub fn register_compute(
+        &mut self,
+        manifest: ComputePrimitiveManifest,
+    ) -> Result<(), CatalogError> {
         let inputs = manifest
             .inputs
             .into_iter()
@@ -150,6 +339,10 @@ impl CorePrimitiveCatalog {
                 name: i.name,
                 value_type: map_common_value_type(i.value_type),
                 required: i.required,
+                cardinality: match i.cardinality {
+                    crate::compute::Cardinality::Single => Cardinality::Single,
+                    crate::compute::Cardinality::Multiple => Cardinality::Multiple,
+                },
             })
             .collect();
 
@@ -167,17 +360,28 @@ impl CorePrimitiveCatalog {
             })
             .collect();
 
+        let parameters = manifest
+            .parameters
+            .into_iter()
+            .map(|spec| map_parameter(&manifest.id, spec))
+            .collect::<Result<_, _>>()?;
+
         self.metadata.insert(
             (manifest.id.clone(), manifest.version.clone()),
             PrimitiveMetadata {
                 kind: PrimitiveKind::Compute,
                 inputs,
                 outputs,
+                parameters,
             },
         );
+        Ok(())
     }
 
-    pub fn register_trigger(&mut self, manifest: crate::trigger::TriggerPrimitiveManifest) {
+    pub fn register_trigger(
+        &mut self,
+        manifest: crate::trigger::TriggerPrimitiveManifest,
+    ) -> Result<(), CatalogError> {
         let inputs = manifest
             .inputs
             .into_iter()
@@ -185,6 +389,10 @@ impl CorePrimitiveCatalog {
                 name: i.name,
                 value_type: map_trigger_value_type(i.value_type),
                 required: i.required,
+                cardinality: match i.cardinality {
+                    crate::trigger::Cardinality::Single => Cardinality::Single,
+                    crate::trigger::Cardinality::Multiple => Cardinality::Multiple,
+                },
             })
             .collect();
 
@@ -202,17 +410,28 @@ impl CorePrimitiveCatalog {
             })
             .collect();
 
+        let parameters = manifest
+            .parameters
+            .into_iter()
+            .map(|spec| map_parameter(&manifest.id, spec))
+            .collect::<Result<_, _>>()?;
+
         self.metadata.insert(
             (manifest.id.clone(), manifest.version.clone()),
             PrimitiveMetadata {
                 kind: PrimitiveKind::Trigger,
                 inputs,
                 outputs,
+                parameters,
             },
         );
+        Ok(())
     }
 
-    pub fn register_source(&mut self, manifest: crate::source::SourcePrimitiveManifest) {
+    pub fn register_source(
+        &mut self,
+        manifest: crate::source::SourcePrimitiveManifest,
+    ) -> Result<(), CatalogError> {
         let inputs = vec![];
         let outputs = manifest
             .outputs
@@ -228,17 +447,28 @@ impl CorePrimitiveCatalog {
             })
             .collect();
 
+        let parameters = manifest
+            .parameters
+            .into_iter()
+            .map(|spec| map_parameter(&manifest.id, spec))
+            .collect::<Result<_, _>>()?;
+
         self.metadata.insert(
             (manifest.id.clone(), manifest.version.clone()),
             PrimitiveMetadata {
                 kind: PrimitiveKind::Source,
                 inputs,
                 outputs,
+                parameters,
             },
         );
+        Ok(())
     }
 
-    pub fn register_action(&mut self, manifest: crate::action::ActionPrimitiveManifest) {
+    pub fn register_action(
+        &mut self,
+        manifest: crate::action::ActionPrimitiveManifest,
+    ) -> Result<(), CatalogError> {
         let inputs = manifest
             .inputs
             .into_iter()
@@ -246,6 +476,9 @@ impl CorePrimitiveCatalog {
                 name: i.name,
                 value_type: map_action_value_type(i.value_type),
                 required: i.required,
+                cardinality: match i.cardinality {
+                    crate::action::Cardinality::Single => Cardinality::Single,
+                },
             })
             .collect();
 
@@ -263,14 +496,22 @@ impl CorePrimitiveCatalog {
             })
             .collect();
 
+        let parameters = manifest
+            .parameters
+            .into_iter()
+            .map(|spec| map_parameter(&manifest.id, spec))
+            .collect::<Result<_, _>>()?;
+
         self.metadata.insert(
             (manifest.id.clone(), manifest.version.clone()),
             PrimitiveMetadata {
                 kind: PrimitiveKind::Action,
                 inputs,
                 outputs,
+                parameters,
             },
         );
+        Ok(())
     }
 }
 
@@ -282,38 +523,102 @@ impl PrimitiveCatalog for CorePrimitiveCatalog {
     }
 }
 
+/// Core manifests are also checked by `core_registries`, so registering them here
+/// cannot fail.
 pub fn build_core_catalog() -> CorePrimitiveCatalog {
     let mut catalog = CorePrimitiveCatalog::new();
+    register_core_manifests(&mut catalog).expect("core manifests declare valid parameters");
+    catalog
+}
 
+fn register_core_manifests(catalog: &mut CorePrimitiveCatalog) -> Result<(), CatalogError> {
     // Sources
-    catalog.register_source(number_source_manifest());
-    catalog.register_source(boolean_source_manifest());
+    catalog.register_source(number_source_manifest())?;
+    catalog.register_source(boolean_source_manifest())?;
+    #[cfg(feature = "serde")]
+    catalog.register_source(payload_number_source_manifest())?;
+    catalog.register_source(string_source_manifest())?;
+    catalog.register_source(state_read_number_source_manifest())?;
+    catalog.register_source(state_read_bool_source_manifest())?;
 
     // Computes
-    catalog.register_compute(const_number_manifest());
-    catalog.register_compute(const_bool_manifest());
-    catalog.register_compute(add_manifest());
-    catalog.register_compute(subtract_manifest());
-    catalog.register_compute(multiply_manifest());
-    catalog.register_compute(divide_manifest());
-    catalog.register_compute(negate_manifest());
-    catalog.register_compute(gt_manifest());
-    catalog.register_compute(lt_manifest());
-    catalog.register_compute(eq_manifest());
-    catalog.register_compute(neq_manifest());
-    catalog.register_compute(and_manifest());
-    catalog.register_compute(or_manifest());
-    catalog.register_compute(not_manifest());
-    catalog.register_compute(select_manifest());
+    catalog.register_compute(const_number_manifest())?;
+    catalog.register_compute(const_bool_manifest())?;
+    catalog.register_compute(add_manifest())?;
+    catalog.register_compute(subtract_manifest())?;
+    catalog.register_compute(multiply_manifest())?;
+    catalog.register_compute(divide_manifest())?;
+    catalog.register_compute(safe_divide_manifest())?;
+    catalog.register_compute(negate_manifest())?;
+    catalog.register_compute(gt_manifest())?;
+    catalog.register_compute(lt_manifest())?;
+    catalog.register_compute(eq_manifest())?;
+    catalog.register_compute(neq_manifest())?;
+    catalog.register_compute(and_manifest())?;
+    catalog.register_compute(or_manifest())?;
+    catalog.register_compute(not_manifest())?;
+    catalog.register_compute(select_manifest())?;
+
+    // Comparison and logic
+    catalog.register_compute(gte_manifest())?;
+    catalog.register_compute(lte_manifest())?;
+    catalog.register_compute(between_manifest())?;
+    catalog.register_compute(approx_eq_manifest())?;
+    catalog.register_compute(xor_manifest())?;
+    catalog.register_compute(nand_manifest())?;
+    catalog.register_compute(implies_manifest())?;
+
+    // Series
+    catalog.register_compute(len_manifest())?;
+    catalog.register_compute(last_manifest())?;
+    catalog.register_compute(at_manifest())?;
+    catalog.register_compute(slice_manifest())?;
+    catalog.register_compute(lag_manifest())?;
+    catalog.register_compute(rolling_mean_manifest())?;
+    catalog.register_compute(rolling_sum_manifest())?;
+    catalog.register_compute(rolling_min_manifest())?;
+    catalog.register_compute(rolling_max_manifest())?;
+    catalog.register_compute(rolling_stdev_manifest())?;
+    catalog.register_compute(cumsum_manifest())?;
+    catalog.register_compute(diff_manifest())?;
+
+    // Numeric
+    catalog.register_compute(abs_manifest())?;
+    catalog.register_compute(min_manifest())?;
+    catalog.register_compute(max_manifest())?;
+    catalog.register_compute(clamp_manifest())?;
+    catalog.register_compute(pow_manifest())?;
+    catalog.register_compute(sqrt_manifest())?;
+    catalog.register_compute(ln_manifest())?;
+    catalog.register_compute(exp_manifest())?;
+    catalog.register_compute(round_manifest())?;
+    catalog.register_compute(floor_manifest())?;
+    catalog.register_compute(ceil_manifest())?;
+    catalog.register_compute(modulo_manifest())?;
+    catalog.register_compute(sign_manifest())?;
+
+    // Strings
+    catalog.register_compute(concat_manifest())?;
+    catalog.register_compute(format_manifest())?;
+    catalog.register_compute(str_eq_manifest())?;
+    catalog.register_compute(str_contains_manifest())?;
+    catalog.register_compute(to_string_manifest())?;
 
     // Triggers
-    catalog.register_trigger(emit_if_true_manifest());
+    catalog.register_trigger(emit_if_true_manifest())?;
+    catalog.register_trigger(emit_if_false_manifest())?;
+    catalog.register_trigger(emit_if_all_manifest())?;
+    catalog.register_trigger(emit_if_any_manifest())?;
+    catalog.register_trigger(emit_if_above_manifest())?;
+    catalog.register_trigger(emit_if_below_manifest())?;
+    catalog.register_trigger(emit_if_changed_from_manifest())?;
 
     // Actions
-    catalog.register_action(ack_action_manifest());
-    catalog.register_action(annotate_action_manifest());
+    catalog.register_action(ack_action_manifest())?;
+    catalog.register_action(annotate_action_manifest())?;
+    catalog.register_action(state_write_action_manifest())?;
 
-    catalog
+    Ok(())
 }
 
 fn map_common_value_type(value_type: common::ValueType) -> ValueType {
@@ -321,6 +626,8 @@ fn map_common_value_type(value_type: common::ValueType) -> ValueType {
         common::ValueType::Number => ValueType::Number,
         common::ValueType::Series => ValueType::Series,
         common::ValueType::Bool => ValueType::Bool,
+        common::ValueType::String => ValueType::String,
+        common::ValueType::Var(variable) => ValueType::Var(variable),
     }
 }
 
@@ -341,3 +648,25 @@ fn map_action_value_type(value_type: ActionValueType) -> ValueType {
         ActionValueType::String => ValueType::String,
     }
 }
+
+/// Stores the parameter's bounds parsed, so validation never parses them again.
+fn map_parameter(
+    primitive: &str,
+    spec: common::ParameterSpec,
+) -> Result<ParameterSpec, CatalogError> {
+    let bounds =
+        spec.parsed_bounds()
+            .map_err(|error| CatalogError::InvalidParameterDeclaration {
+                primitive: primitive.to_string(),
+                parameter: spec.name.clone(),
+                error,
+            })?;
+    Ok(ParameterSpec {
+        name: spec.name,
+        ty: spec.value_type,
+        required: spec.default.is_none(),
+        default: spec.default,
+        bounds,
+        variants: spec.variants,
+    })
+}
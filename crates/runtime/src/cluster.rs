@@ -1,9 +1,15 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::common::bounds::Bounds;
+use crate::common::parameter::{check_declaration, ParameterDeclarationError};
+
+pub use crate::common::parameter::{ParameterType, ParameterValue};
+
 pub type Version = String;
 pub type NodeId = String;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClusterDefinition {
     pub id: String,
     pub version: Version,
@@ -16,6 +22,7 @@ pub struct ClusterDefinition {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NodeInstance {
     pub id: NodeId,
     pub kind: NodeKind,
@@ -23,6 +30,8 @@ pub struct NodeInstance {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum NodeKind {
     Impl {
         impl_id: String,
@@ -35,36 +44,42 @@ pub enum NodeKind {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Edge {
     pub from: OutputRef,
     pub to: InputRef,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OutputRef {
     pub node_id: NodeId,
     pub port_name: String,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InputRef {
     pub node_id: NodeId,
     pub port_name: String,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InputPortSpec {
     pub name: String,
     pub maps_to: GraphInputPlaceholder,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OutputPortSpec {
     pub name: String,
     pub maps_to: OutputRef,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphInputPlaceholder {
     pub name: String,
     pub ty: ValueType,
@@ -72,20 +87,30 @@ pub struct GraphInputPlaceholder {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParameterSpec {
     pub name: String,
     pub ty: ParameterType,
     pub default: Option<ParameterValue>,
     pub required: bool,
+    /// See `common::bounds` for the grammar.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bounds: Option<Bounds>,
+    /// Allowed values of an Enum parameter; empty for every other type.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub variants: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum ParameterBinding {
     Literal { value: ParameterValue },
     Exposed { parent_param: String },
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Signature {
     pub kind: BoundaryKind,
     pub inputs: Vec<PortSpec>,
@@ -95,6 +120,7 @@ pub struct Signature {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PortSpec {
     pub name: String,
     pub ty: ValueType,
@@ -103,6 +129,7 @@ pub struct PortSpec {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BoundaryKind {
     SourceLike,
     ComputeLike,
@@ -111,39 +138,26 @@ pub enum BoundaryKind {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ValueType {
     Number,
     Series,
     Bool,
     Event,
     String,
+    /// A type variable declared by a primitive manifest; see `common::ValueType::Var`.
+    Var(char),
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Cardinality {
     Single,
     Multiple,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum ParameterType {
-    Int,
-    Number,
-    Bool,
-    String,
-    Enum,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum ParameterValue {
-    Int(i64),
-    Number(f64),
-    Bool(bool),
-    String(String),
-    Enum(String),
-}
-
-#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrimitiveKind {
     Source,
     Compute,
@@ -162,6 +176,8 @@ pub struct PrimitiveMetadata {
     pub kind: PrimitiveKind,
     pub inputs: Vec<InputMetadata>,
     pub outputs: HashMap<String, OutputMetadata>,
+    /// Declared parameters; a parameter without a default is required.
+    pub parameters: Vec<ParameterSpec>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -169,12 +185,15 @@ pub struct InputMetadata {
     pub name: String,
     pub value_type: ValueType,
     pub required: bool,
+    /// `Single` accepts at most one edge; `Multiple` gathers every edge into a list.
+    pub cardinality: Cardinality,
 }
 
 /// Expansion output. Contains only topology, primitive identity, and authoring trace.
 /// `boundary_inputs` and `boundary_outputs` are retained for signature inference only
 /// and must not influence runtime execution.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpandedGraph {
     pub nodes: HashMap<String, ExpandedNode>,
     pub edges: Vec<ExpandedEdge>,
@@ -189,6 +208,7 @@ pub struct ExpandedGraph {
 /// have no cluster representation. The type system guarantees authoring
 /// constructs cannot reach execution.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpandedNode {
     pub runtime_id: String,
     pub authoring_path: Vec<(String, NodeId)>,
@@ -197,6 +217,7 @@ pub struct ExpandedNode {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImplementationInstance {
     // Identity-only; no semantic or configuration fields.
     pub impl_id: String,
@@ -204,12 +225,15 @@ pub struct ImplementationInstance {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExpandedEdge {
     pub from: ExpandedEndpoint,
     pub to: ExpandedEndpoint,
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
 pub enum ExpandedEndpoint {
     NodePort { node_id: String, port_name: String },
     ExternalInput { name: String },
@@ -227,6 +251,10 @@ pub enum ExpandError {
         expected: ParameterType,
         got: ParameterType,
     },
+    InvalidParameterDeclaration {
+        name: String,
+        error: ParameterDeclarationError,
+    },
     SignatureInferenceFailed(SignatureInferenceError),
     DeclaredSignatureInvalid(ClusterValidationError),
     /// I.3: Required parameter has no binding and no default
@@ -254,6 +282,23 @@ pub enum ExpandError {
         expected: ParameterType,
         got: ParameterType,
     },
+    /// Literal binding falls outside the parameter's declared bounds
+    ParameterBindingOutOfBounds {
+        cluster_id: String,
+        parameter: String,
+    },
+    /// Literal Enum binding names a variant the parameter does not declare
+    ParameterBindingUnknownVariant {
+        cluster_id: String,
+        parameter: String,
+        variant: String,
+    },
+    /// Exposed Enum binding: the parent parameter admits variants the nested one does not
+    ExposedParameterVariantsMismatch {
+        cluster_id: String,
+        parameter: String,
+        unknown: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -349,7 +394,7 @@ fn validate_cluster_definition(cluster_def: &ClusterDefinition) -> Result<(), Ex
         }
 
         if let Some(default) = &param.default {
-            let got = parameter_value_type(default);
+            let got = default.value_type();
             if got != param.ty {
                 return Err(ExpandError::ParameterDefaultTypeMismatch {
                     name: param.name.clone(),
@@ -358,21 +403,22 @@ fn validate_cluster_definition(cluster_def: &ClusterDefinition) -> Result<(), Ex
                 });
             }
         }
+
+        check_declaration(
+            &param.ty,
+            param.default.as_ref(),
+            &param.variants,
+            param.bounds.as_ref(),
+        )
+        .map_err(|error| ExpandError::InvalidParameterDeclaration {
+            name: param.name.clone(),
+            error,
+        })?;
     }
 
     Ok(())
 }
 
-fn parameter_value_type(value: &ParameterValue) -> ParameterType {
-    match value {
-        ParameterValue::Int(_) => ParameterType::Int,
-        ParameterValue::Number(_) => ParameterType::Number,
-        ParameterValue::Bool(_) => ParameterType::Bool,
-        ParameterValue::String(_) => ParameterType::String,
-        ParameterValue::Enum(_) => ParameterType::Enum,
-    }
-}
-
 /// Infers the cluster's signature from its expanded graph.
 ///
 /// F.6 invariant: Inference depends only on:
@@ -444,15 +490,24 @@ pub fn infer_signature<C: PrimitiveCatalog>(
             }
         })?;
 
+        let ty = resolve_port_type(
+            graph,
+            &node_meta,
+            &output.maps_to.node_id,
+            &out_meta.value_type,
+            graph.nodes.len(),
+        )
+        .unwrap_or_else(|| out_meta.value_type.clone());
+
         let wireable = meta.kind != PrimitiveKind::Action;
         if wireable {
             has_wireable_outputs = true;
-            wireable_out_types.push(out_meta.value_type.clone());
+            wireable_out_types.push(ty.clone());
         }
 
         outputs.push(PortSpec {
             name: output.name.clone(),
-            ty: out_meta.value_type.clone(),
+            ty,
             cardinality: out_meta.cardinality.clone(),
             wireable,
         });
@@ -490,6 +545,53 @@ pub fn infer_signature<C: PrimitiveCatalog>(
     })
 }
 
+/// Resolves a port type on `node_id`. A type variable takes the type of the first edge,
+/// in edge order, feeding an input of that node declared with the same variable, as
+/// runtime validation binds it. `None` when nothing binds it within `depth` hops.
+fn resolve_port_type(
+    graph: &ExpandedGraph,
+    node_meta: &HashMap<String, PrimitiveMetadata>,
+    node_id: &str,
+    ty: &ValueType,
+    depth: usize,
+) -> Option<ValueType> {
+    if !matches!(ty, ValueType::Var(_)) {
+        return Some(ty.clone());
+    }
+    let depth = depth.checked_sub(1)?;
+    let meta = node_meta.get(node_id)?;
+    graph.edges.iter().find_map(|edge| {
+        let ExpandedEndpoint::NodePort {
+            node_id: to,
+            port_name,
+        } = &edge.to
+        else {
+            return None;
+        };
+        if to != node_id
+            || !meta
+                .inputs
+                .iter()
+                .any(|input| input.name == *port_name && input.value_type == *ty)
+        {
+            return None;
+        }
+        match &edge.from {
+            ExpandedEndpoint::NodePort { node_id, port_name } => {
+                let source = node_meta.get(node_id)?.outputs.get(port_name)?;
+                resolve_port_type(graph, node_meta, node_id, &source.value_type, depth)
+            }
+            // External inputs are keyed by `external_key`, which ends in the
+            // placeholder name.
+            ExpandedEndpoint::ExternalInput { name } => graph
+                .boundary_inputs
+                .iter()
+                .find(|input| name.rsplit('/').next() == Some(input.maps_to.name.as_str()))
+                .map(|input| input.maps_to.ty.clone()),
+        }
+    })
+}
+
 /// D.11: Validate that declared signature wireability does not exceed inferred wireability.
 /// Declared wireability can restrict (true → false) but cannot grant (false → true).
 pub fn validate_declared_signature(
@@ -594,7 +696,7 @@ fn validate_parameter_bindings(
             }
             Some(ParameterBinding::Literal { value }) => {
                 // I.4: Literal binding must have correct type
-                let got = parameter_value_type(value);
+                let got = value.value_type();
                 if got != param_spec.ty {
                     return Err(ExpandError::ParameterBindingTypeMismatch {
                         cluster_id: nested_def.id.clone(),
@@ -603,6 +705,23 @@ fn validate_parameter_bindings(
                         got,
                     });
                 }
+                if let ParameterValue::Enum(variant) = value {
+                    if !param_spec.variants.contains(variant) {
+                        return Err(ExpandError::ParameterBindingUnknownVariant {
+                            cluster_id: nested_def.id.clone(),
+                            parameter: param_spec.name.clone(),
+                            variant: variant.clone(),
+                        });
+                    }
+                }
+                if let Some(bounds) = &param_spec.bounds {
+                    if !value.bounded().is_some_and(|v| bounds.admits(v)) {
+                        return Err(ExpandError::ParameterBindingOutOfBounds {
+                            cluster_id: nested_def.id.clone(),
+                            parameter: param_spec.name.clone(),
+                        });
+                    }
+                }
             }
             Some(ParameterBinding::Exposed { parent_param }) => {
                 // I.5: Exposed binding must reference existing parent parameter
@@ -624,7 +743,22 @@ fn validate_parameter_bindings(
                             got: spec.ty.clone(),
                         });
                     }
-                    Some(_) => {} // Type matches, binding valid
+                    Some(spec) => {
+                        // Every value the parent admits must be admitted here too
+                        let unknown: Vec<String> = spec
+                            .variants
+                            .iter()
+                            .filter(|v| !param_spec.variants.contains(v))
+                            .cloned()
+                            .collect();
+                        if !unknown.is_empty() {
+                            return Err(ExpandError::ExposedParameterVariantsMismatch {
+                                cluster_id: nested_def.id.clone(),
+                                parameter: param_spec.name.clone(),
+                                unknown,
+                            });
+                        }
+                    }
                 }
             }
         }
@@ -992,6 +1126,7 @@ mod tests {
             kind,
             inputs: Vec::new(),
             outputs: outputs_map,
+            parameters: Vec::new(),
         }
     }
 
@@ -1404,6 +1539,88 @@ mod tests {
         assert!(!sig.has_side_effects);
     }
 
+    #[test]
+    fn infers_output_type_bound_through_a_type_variable() {
+        let impl_node = |id: &str, impl_id: &str| {
+            (
+                id.to_string(),
+                NodeInstance {
+                    id: id.to_string(),
+                    kind: NodeKind::Impl {
+                        impl_id: impl_id.to_string(),
+                        version: "v1".to_string(),
+                    },
+                    parameter_bindings: HashMap::new(),
+                },
+            )
+        };
+        let edge = |from: &str, output: &str, to: &str, input: &str| Edge {
+            from: OutputRef {
+                node_id: from.to_string(),
+                port_name: output.to_string(),
+            },
+            to: InputRef {
+                node_id: to.to_string(),
+                port_name: input.to_string(),
+            },
+        };
+        // pick(a: T, b: T) -> T, fed by a Series source and a Bool placeholder.
+        let cluster = ClusterDefinition {
+            id: "root".to_string(),
+            version: "v1".to_string(),
+            nodes: HashMap::from([impl_node("s", "series"), impl_node("p", "pick")]),
+            edges: vec![edge("s", "value", "p", "a"), edge("in", "value", "p", "b")],
+            input_ports: vec![InputPortSpec {
+                name: "in".to_string(),
+                maps_to: GraphInputPlaceholder {
+                    name: "in".to_string(),
+                    ty: ValueType::Bool,
+                    required: true,
+                },
+            }],
+            output_ports: vec![OutputPortSpec {
+                name: "out".to_string(),
+                maps_to: OutputRef {
+                    node_id: "p".to_string(),
+                    port_name: "result".to_string(),
+                },
+            }],
+            parameters: empty_parameters(),
+            declared_signature: None,
+        };
+
+        let loader = TestLoader::new();
+        let expanded = expand(&cluster, &loader, &TestCatalog::default()).unwrap();
+
+        let variable = |name: &str| InputMetadata {
+            name: name.to_string(),
+            value_type: ValueType::Var('T'),
+            required: true,
+            cardinality: Cardinality::Single,
+        };
+        let mut pick = meta(PrimitiveKind::Compute, &[("result", ValueType::Var('T'))]);
+        pick.inputs = vec![variable("a"), variable("b")];
+        let catalog = TestCatalog::default()
+            .with_metadata(
+                "series",
+                "v1",
+                meta(PrimitiveKind::Source, &[("value", ValueType::Series)]),
+            )
+            .with_metadata("pick", "v1", pick);
+
+        let sig = infer_signature(&expanded, &catalog).unwrap();
+        assert_eq!(sig.outputs[0].ty, ValueType::Series);
+
+        // With only the placeholder wired, it binds T.
+        let mut expanded = expanded;
+        expanded
+            .edges
+            .retain(|e| matches!(e.from, ExpandedEndpoint::ExternalInput { .. }));
+        let sig = infer_signature(&expanded, &catalog).unwrap();
+        assert_eq!(sig.outputs[0].ty, ValueType::Bool);
+        assert_eq!(sig.kind, BoundaryKind::ComputeLike);
+    }
+
     /// F.1 invariant test: Input ports must never be wireable (CLUSTER_SPEC.md §3.2)
     #[test]
     fn input_ports_are_never_wireable() {
@@ -1762,12 +1979,16 @@ mod tests {
                     ty: ParameterType::Number,
                     default: None,
                     required: true,
+                    bounds: None,
+                    variants: Vec::new(),
                 },
                 ParameterSpec {
                     name: "p".to_string(),
                     ty: ParameterType::Number,
                     default: None,
                     required: true,
+                    bounds: None,
+                    variants: Vec::new(),
                 },
             ],
             declared_signature: None,
@@ -1810,6 +2031,8 @@ mod tests {
                 ty: ParameterType::Bool,
                 default: Some(ParameterValue::Number(1.0)),
                 required: false,
+                bounds: None,
+                variants: Vec::new(),
             }],
             declared_signature: None,
         };
@@ -1857,6 +2080,8 @@ mod tests {
                 ty: ParameterType::Number,
                 default: None, // No default
                 required: true,
+                bounds: None,
+                variants: Vec::new(),
             }],
             declared_signature: None,
         };
@@ -1928,6 +2153,8 @@ mod tests {
                 ty: ParameterType::Number,
                 default: None,
                 required: true,
+                bounds: None,
+                variants: Vec::new(),
             }],
             declared_signature: None,
         };
@@ -1980,6 +2207,183 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parameter_binding_out_of_bounds_rejected() {
+        // Inner cluster bounds its lookback to positive integers
+        let mut inner_nodes = HashMap::new();
+        inner_nodes.insert(
+            "leaf".to_string(),
+            NodeInstance {
+                id: "leaf".to_string(),
+                kind: NodeKind::Impl {
+                    impl_id: "prim".to_string(),
+                    version: "v1".to_string(),
+                },
+                parameter_bindings: HashMap::new(),
+            },
+        );
+
+        let inner = ClusterDefinition {
+            id: "inner".to_string(),
+            version: "v1".to_string(),
+            nodes: inner_nodes,
+            edges: Vec::new(),
+            input_ports: Vec::new(),
+            output_ports: Vec::new(),
+            parameters: vec![ParameterSpec {
+                name: "lookback".to_string(),
+                ty: ParameterType::Int,
+                default: None,
+                required: true,
+                bounds: Some(Bounds::parse("[1, )").unwrap()),
+                variants: Vec::new(),
+            }],
+            declared_signature: None,
+        };
+
+        // Outer cluster binds a negative lookback
+        let mut outer_nodes = HashMap::new();
+        outer_nodes.insert(
+            "nested".to_string(),
+            NodeInstance {
+                id: "nested".to_string(),
+                kind: NodeKind::Cluster {
+                    cluster_id: "inner".to_string(),
+                    version: "v1".to_string(),
+                },
+                parameter_bindings: HashMap::from([(
+                    "lookback".to_string(),
+                    ParameterBinding::Literal {
+                        value: ParameterValue::Int(-5),
+                    },
+                )]),
+            },
+        );
+
+        let outer = ClusterDefinition {
+            id: "outer".to_string(),
+            version: "v1".to_string(),
+            nodes: outer_nodes,
+            edges: Vec::new(),
+            input_ports: Vec::new(),
+            output_ports: Vec::new(),
+            parameters: Vec::new(),
+            declared_signature: None,
+        };
+
+        let loader = TestLoader::new().with_cluster(inner);
+        let catalog = TestCatalog::default();
+        let result = expand(&outer, &loader, &catalog);
+
+        assert_eq!(
+            result,
+            Err(ExpandError::ParameterBindingOutOfBounds {
+                cluster_id: "inner".to_string(),
+                parameter: "lookback".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn enum_binding_outside_declared_variants_rejected() {
+        let mut inner_nodes = HashMap::new();
+        inner_nodes.insert(
+            "leaf".to_string(),
+            NodeInstance {
+                id: "leaf".to_string(),
+                kind: NodeKind::Impl {
+                    impl_id: "prim".to_string(),
+                    version: "v1".to_string(),
+                },
+                parameter_bindings: HashMap::new(),
+            },
+        );
+
+        let inner = ClusterDefinition {
+            id: "inner".to_string(),
+            version: "v1".to_string(),
+            nodes: inner_nodes,
+            edges: Vec::new(),
+            input_ports: Vec::new(),
+            output_ports: Vec::new(),
+            parameters: vec![ParameterSpec {
+                name: "side".to_string(),
+                ty: ParameterType::Enum,
+                default: Some(ParameterValue::Enum("buy".to_string())),
+                required: false,
+                bounds: None,
+                variants: vec!["buy".to_string(), "sell".to_string()],
+            }],
+            declared_signature: None,
+        };
+
+        let outer = |binding: ParameterBinding, parameters: Vec<ParameterSpec>| {
+            let mut outer_nodes = HashMap::new();
+            outer_nodes.insert(
+                "nested".to_string(),
+                NodeInstance {
+                    id: "nested".to_string(),
+                    kind: NodeKind::Cluster {
+                        cluster_id: "inner".to_string(),
+                        version: "v1".to_string(),
+                    },
+                    parameter_bindings: HashMap::from([("side".to_string(), binding)]),
+                },
+            );
+            ClusterDefinition {
+                id: "outer".to_string(),
+                version: "v1".to_string(),
+                nodes: outer_nodes,
+                edges: Vec::new(),
+                input_ports: Vec::new(),
+                output_ports: Vec::new(),
+                parameters,
+                declared_signature: None,
+            }
+        };
+
+        let loader = TestLoader::new().with_cluster(inner);
+        let catalog = TestCatalog::default();
+
+        let literal = outer(
+            ParameterBinding::Literal {
+                value: ParameterValue::Enum("hold".to_string()),
+            },
+            Vec::new(),
+        );
+        assert_eq!(
+            expand(&literal, &loader, &catalog),
+            Err(ExpandError::ParameterBindingUnknownVariant {
+                cluster_id: "inner".to_string(),
+                parameter: "side".to_string(),
+                variant: "hold".to_string(),
+            })
+        );
+
+        // The parent may narrow the variant set but not widen it
+        let exposed = outer(
+            ParameterBinding::Exposed {
+                parent_param: "side".to_string(),
+            },
+            vec![ParameterSpec {
+                name: "side".to_string(),
+                ty: ParameterType::Enum,
+                default: None,
+                required: true,
+                bounds: None,
+                variants: vec!["sell".to_string(), "hold".to_string()],
+            }],
+        );
+        assert_eq!(
+            expand(&exposed, &loader, &catalog),
+            Err(ExpandError::ExposedParameterVariantsMismatch {
+                cluster_id: "inner".to_string(),
+                parameter: "side".to_string(),
+                unknown: vec!["hold".to_string()],
+            })
+        );
+    }
+
     /// I.5: Exposed binding referencing nonexistent parent parameter must be rejected
     #[test]
     fn exposed_parameter_not_in_parent_rejected() {
@@ -2009,6 +2413,8 @@ mod tests {
                 ty: ParameterType::Number,
                 default: None,
                 required: true,
+                bounds: None,
+                variants: Vec::new(),
             }],
             declared_signature: None,
         };
@@ -2088,6 +2494,8 @@ mod tests {
                 ty: ParameterType::Number, // Expects Number
                 default: None,
                 required: true,
+                bounds: None,
+                variants: Vec::new(),
             }],
             declared_signature: None,
         };
@@ -2123,6 +2531,8 @@ mod tests {
                 ty: ParameterType::Int, // Int, not Number!
                 default: None,
                 required: true,
+                bounds: None,
+                variants: Vec::new(),
             }],
             declared_signature: None,
         };
@@ -2144,4 +2554,86 @@ mod tests {
                 && got == ParameterType::Int
         ));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn cluster_definition_round_trips_through_json() {
+        let def = ClusterDefinition {
+            id: "outer".to_string(),
+            version: "1.0.0".to_string(),
+            nodes: HashMap::from([(
+                "n1".to_string(),
+                NodeInstance {
+                    id: "n1".to_string(),
+                    kind: NodeKind::Impl {
+                        impl_id: "gt".to_string(),
+                        version: "0.1.0".to_string(),
+                    },
+                    parameter_bindings: HashMap::from([
+                        (
+                            "threshold".to_string(),
+                            ParameterBinding::Exposed {
+                                parent_param: "limit".to_string(),
+                            },
+                        ),
+                        (
+                            "count".to_string(),
+                            ParameterBinding::Literal {
+                                value: ParameterValue::Int(3),
+                            },
+                        ),
+                    ]),
+                },
+            )]),
+            edges: Vec::new(),
+            input_ports: vec![InputPortSpec {
+                name: "x".to_string(),
+                maps_to: GraphInputPlaceholder {
+                    name: "x".to_string(),
+                    ty: ValueType::Number,
+                    required: true,
+                },
+            }],
+            output_ports: vec![OutputPortSpec {
+                name: "out".to_string(),
+                maps_to: OutputRef {
+                    node_id: "n1".to_string(),
+                    port_name: "result".to_string(),
+                },
+            }],
+            parameters: vec![ParameterSpec {
+                name: "limit".to_string(),
+                ty: ParameterType::Number,
+                default: Some(ParameterValue::Number(1.0)),
+                required: false,
+                bounds: None,
+                variants: Vec::new(),
+            }],
+            declared_signature: Some(Signature {
+                kind: BoundaryKind::ComputeLike,
+                inputs: Vec::new(),
+                outputs: vec![PortSpec {
+                    name: "out".to_string(),
+                    ty: ValueType::Bool,
+                    cardinality: Cardinality::Single,
+                    wireable: true,
+                }],
+                has_side_effects: false,
+                is_origin: false,
+            }),
+        };
+
+        let json = serde_json::to_value(&def).unwrap();
+        assert_eq!(
+            json["nodes"]["n1"]["kind"],
+            serde_json::json!({ "type": "Impl", "impl_id": "gt", "version": "0.1.0" })
+        );
+        assert_eq!(
+            json["nodes"]["n1"]["parameter_bindings"]["count"],
+            serde_json::json!({ "type": "Literal", "value": { "type": "Int", "value": 3 } })
+        );
+
+        let decoded: ClusterDefinition = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, def);
+    }
 }
@@ -0,0 +1,324 @@
+//! Parameter bounds grammar.
+//!
+//! `ParameterSpec.bounds` holds one of:
+//!
+//! - `[0, 10]`, `(0, 10)`, `[0, 10)`, `(0, 10]`: numeric interval. `[` and `]` are
+//!   closed, `(` and `)` open; an empty endpoint is unbounded, as in `[1, )`.
+//! - `len[1, 32]`: string length in characters, with the same interval syntax.
+//! - `matches /^[A-Z]{3}$/`: the string must match the regular expression.
+//! - `one of {buy, sell}`: the string must be one of the listed values.
+//!
+//! Intervals apply to Int and Number parameters; the other forms apply to String
+//! and Enum parameters. Bool parameters take no bounds.
+//!
+//! Manifests declare bounds as text. Catalog metadata and cluster definitions hold
+//! them parsed, and they print back in the grammar above.
+
+use std::fmt;
+
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub enum Bounds {
+    Range(Interval),
+    Length(Interval),
+    Pattern(Regex),
+    OneOf(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interval {
+    pub min: Option<Limit>,
+    pub max: Option<Limit>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Limit {
+    pub value: f64,
+    pub inclusive: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BoundsError {
+    Syntax(String),
+    InvalidPattern(String),
+    /// The bounds form does not apply to the parameter's type.
+    NotApplicable,
+}
+
+/// Which bounds forms a parameter type accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoundedKind {
+    Number,
+    Text,
+}
+
+/// A parameter value as seen by a bounds check.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bounded<'a> {
+    Number(f64),
+    Text(&'a str),
+}
+
+impl Bounds {
+    pub fn parse(text: &str) -> Result<Self, BoundsError> {
+        let text = text.trim();
+        if let Some(rest) = text.strip_prefix("len") {
+            return parse_interval(rest.trim_start()).map(Bounds::Length);
+        }
+        if let Some(rest) = text.strip_prefix("matches") {
+            let pattern = rest
+                .trim()
+                .strip_prefix('/')
+                .and_then(|p| p.strip_suffix('/'))
+                .ok_or_else(|| syntax("expected matches /pattern/"))?;
+            return Regex::new(pattern)
+                .map(Bounds::Pattern)
+                .map_err(|e| BoundsError::InvalidPattern(e.to_string()));
+        }
+        if let Some(rest) = text.strip_prefix("one of") {
+            let items = rest
+                .trim()
+                .strip_prefix('{')
+                .and_then(|p| p.strip_suffix('}'))
+                .ok_or_else(|| syntax("expected one of {a, b, ...}"))?;
+            let items: Vec<String> = items.split(',').map(|i| i.trim().to_string()).collect();
+            if items.iter().any(String::is_empty) {
+                return Err(syntax("empty value in one of {...}"));
+            }
+            return Ok(Bounds::OneOf(items));
+        }
+        parse_interval(text).map(Bounds::Range)
+    }
+
+    /// Parses bounds declared for a parameter of `kind`; `None` is a type that takes
+    /// no bounds.
+    pub fn parse_for(text: &str, kind: Option<BoundedKind>) -> Result<Self, BoundsError> {
+        let bounds = Self::parse(text)?;
+        if kind == Some(bounds.kind()) {
+            Ok(bounds)
+        } else {
+            Err(BoundsError::NotApplicable)
+        }
+    }
+
+    pub fn kind(&self) -> BoundedKind {
+        match self {
+            Bounds::Range(_) => BoundedKind::Number,
+            Bounds::Length(_) | Bounds::Pattern(_) | Bounds::OneOf(_) => BoundedKind::Text,
+        }
+    }
+
+    /// A value of the wrong kind is never admitted.
+    pub fn admits(&self, value: Bounded) -> bool {
+        match (self, value) {
+            (Bounds::Range(interval), Bounded::Number(n)) => interval.contains(n),
+            (Bounds::Length(interval), Bounded::Text(s)) => {
+                interval.contains(s.chars().count() as f64)
+            }
+            (Bounds::Pattern(regex), Bounded::Text(s)) => regex.is_match(s),
+            (Bounds::OneOf(items), Bounded::Text(s)) => items.iter().any(|i| i == s),
+            _ => false,
+        }
+    }
+}
+
+/// Patterns compare by their source text.
+impl PartialEq for Bounds {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Bounds::Range(a), Bounds::Range(b)) | (Bounds::Length(a), Bounds::Length(b)) => a == b,
+            (Bounds::Pattern(a), Bounds::Pattern(b)) => a.as_str() == b.as_str(),
+            (Bounds::OneOf(a), Bounds::OneOf(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bounds::Range(interval) => write!(f, "{}", interval),
+            Bounds::Length(interval) => write!(f, "len{}", interval),
+            Bounds::Pattern(regex) => write!(f, "matches /{}/", regex.as_str()),
+            Bounds::OneOf(items) => write!(f, "one of {{{}}}", items.join(", ")),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bounds {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bounds {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Bounds::parse(&text).map_err(|error| {
+            serde::de::Error::custom(format!("invalid bounds '{}': {:?}", text, error))
+        })
+    }
+}
+
+/// An unbounded end prints open, as in `[1, )`.
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.min {
+            Some(min) => write!(
+                f,
+                "{}{}, ",
+                if min.inclusive { '[' } else { '(' },
+                min.value
+            )?,
+            None => write!(f, "(, ")?,
+        }
+        match &self.max {
+            Some(max) => write!(f, "{}{}", max.value, if max.inclusive { ']' } else { ')' }),
+            None => write!(f, ")"),
+        }
+    }
+}
+
+impl Interval {
+    pub fn contains(&self, value: f64) -> bool {
+        let above = self
+            .min
+            .as_ref()
+            .is_none_or(|min| value > min.value || (min.inclusive && value == min.value));
+        let below = self
+            .max
+            .as_ref()
+            .is_none_or(|max| value < max.value || (max.inclusive && value == max.value));
+        above && below
+    }
+}
+
+fn parse_interval(text: &str) -> Result<Interval, BoundsError> {
+    let min_inclusive = match text.chars().next() {
+        Some('[') => true,
+        Some('(') => false,
+        _ => return Err(syntax("expected '[' or '('")),
+    };
+    let max_inclusive = match text.chars().last() {
+        Some(']') => true,
+        Some(')') => false,
+        _ => return Err(syntax("expected ']' or ')'")),
+    };
+    let (min, max) = text[1..text.len() - 1]
+        .split_once(',')
+        .ok_or_else(|| syntax("expected ',' between interval endpoints"))?;
+
+    let interval = Interval {
+        min: parse_limit(min, min_inclusive)?,
+        max: parse_limit(max, max_inclusive)?,
+    };
+    if let (Some(min), Some(max)) = (&interval.min, &interval.max) {
+        if min.value > max.value {
+            return Err(syntax("interval minimum exceeds its maximum"));
+        }
+    }
+    Ok(interval)
+}
+
+fn parse_limit(text: &str, inclusive: bool) -> Result<Option<Limit>, BoundsError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let value: f64 = text
+        .parse()
+        .map_err(|_| syntax(&format!("'{}' is not a number", text)))?;
+    if !value.is_finite() {
+        return Err(syntax("interval endpoints must be finite"));
+    }
+    Ok(Some(Limit { value, inclusive }))
+}
+
+fn syntax(message: &str) -> BoundsError {
+    BoundsError::Syntax(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intervals_respect_open_and_closed_endpoints() {
+        let bounds = Bounds::parse("(0, 10]").unwrap();
+        assert!(!bounds.admits(Bounded::Number(0.0)));
+        assert!(bounds.admits(Bounded::Number(0.5)));
+        assert!(bounds.admits(Bounded::Number(10.0)));
+        assert!(!bounds.admits(Bounded::Number(10.5)));
+
+        let lookback = Bounds::parse("[1, )").unwrap();
+        assert!(lookback.admits(Bounded::Number(1.0)));
+        assert!(lookback.admits(Bounded::Number(1e9)));
+        assert!(!lookback.admits(Bounded::Number(-5.0)));
+    }
+
+    #[test]
+    fn text_bounds_check_length_pattern_and_membership() {
+        let length = Bounds::parse("len[1, 3]").unwrap();
+        assert!(length.admits(Bounded::Text("abc")));
+        assert!(!length.admits(Bounded::Text("")));
+        assert!(!length.admits(Bounded::Text("abcd")));
+
+        let pattern = Bounds::parse("matches /^[A-Z]{3}$/").unwrap();
+        assert!(pattern.admits(Bounded::Text("USD")));
+        assert!(!pattern.admits(Bounded::Text("usd")));
+
+        let side = Bounds::parse("one of {buy, sell}").unwrap();
+        assert!(side.admits(Bounded::Text("sell")));
+        assert!(!side.admits(Bounded::Text("hold")));
+        assert!(!side.admits(Bounded::Number(1.0)));
+    }
+
+    #[test]
+    fn bounds_print_back_in_the_grammar() {
+        for text in [
+            "[1, )",
+            "(0, 10]",
+            "[-2.5, 0)",
+            "len[1, 32]",
+            "matches /^[A-Z]{3}$/",
+            "one of {buy, sell}",
+        ] {
+            let bounds = Bounds::parse(text).unwrap();
+            assert_eq!(bounds.to_string(), text);
+            assert_eq!(Bounds::parse(&bounds.to_string()).unwrap(), bounds);
+        }
+    }
+
+    #[test]
+    fn malformed_or_misapplied_bounds_are_rejected() {
+        for text in [
+            "0, 10",
+            "[0 10]",
+            "[a, 1]",
+            "[5, 1]",
+            "one of {a, }",
+            "matches abc",
+        ] {
+            assert!(
+                matches!(Bounds::parse(text), Err(BoundsError::Syntax(_))),
+                "{}",
+                text
+            );
+        }
+        assert!(matches!(
+            Bounds::parse("matches /(/"),
+            Err(BoundsError::InvalidPattern(_))
+        ));
+        assert_eq!(
+            Bounds::parse_for("len[1, 3]", Some(BoundedKind::Number)).unwrap_err(),
+            BoundsError::NotApplicable
+        );
+        assert_eq!(
+            Bounds::parse_for("[0, 1]", None).unwrap_err(),
+            BoundsError::NotApplicable
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::common::parameter::ParameterDeclarationError;
 use crate::common::value::{PrimitiveKind, ValueType};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -45,6 +46,15 @@ pub enum ValidationError {
         expected: ValueType,
         got: ValueType,
     },
+    InvalidParameterDeclaration {
+        parameter: String,
+        error: ParameterDeclarationError,
+    },
+    /// An output is typed by a variable that no input declares, so nothing binds it.
+    UnboundTypeVariable {
+        output: String,
+        variable: char,
+    },
     UnknownPrimitive(String),
     CycleDetected,
     MissingNode(String),
@@ -1,5 +1,8 @@
+pub mod bounds;
 pub mod errors;
+pub mod parameter;
 pub mod value;
 
 pub use errors::ValidationError;
+pub use parameter::{ParameterSpec, ParameterType, ParameterValue};
 pub use value::{PrimitiveKind, Value, ValueType};
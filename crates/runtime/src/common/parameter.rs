@@ -0,0 +1,133 @@
+//! Parameter declarations shared by every primitive kind and by cluster definitions.
+
+use super::bounds::{Bounded, BoundedKind, Bounds, BoundsError};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParameterType {
+    Int,
+    Number,
+    Bool,
+    String,
+    Enum,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
+pub enum ParameterValue {
+    Int(i64),
+    Number(f64),
+    Bool(bool),
+    String(String),
+    Enum(String),
+}
+
+impl ParameterValue {
+    pub fn value_type(&self) -> ParameterType {
+        match self {
+            ParameterValue::Int(_) => ParameterType::Int,
+            ParameterValue::Number(_) => ParameterType::Number,
+            ParameterValue::Bool(_) => ParameterType::Bool,
+            ParameterValue::String(_) => ParameterType::String,
+            ParameterValue::Enum(_) => ParameterType::Enum,
+        }
+    }
+
+    pub fn bounded(&self) -> Option<Bounded<'_>> {
+        match self {
+            ParameterValue::Int(i) => Some(Bounded::Number(*i as f64)),
+            ParameterValue::Number(n) => Some(Bounded::Number(*n)),
+            ParameterValue::Bool(_) => None,
+            ParameterValue::String(s) | ParameterValue::Enum(s) => Some(Bounded::Text(s)),
+        }
+    }
+}
+
+impl ParameterType {
+    pub fn bounded_kind(&self) -> Option<BoundedKind> {
+        match self {
+            ParameterType::Int | ParameterType::Number => Some(BoundedKind::Number),
+            ParameterType::Bool => None,
+            ParameterType::String | ParameterType::Enum => Some(BoundedKind::Text),
+        }
+    }
+}
+
+/// A parameter as a primitive manifest declares it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterSpec {
+    pub name: String,
+    pub value_type: ParameterType,
+    pub default: Option<ParameterValue>,
+    /// See `common::bounds` for the grammar.
+    pub bounds: Option<String>,
+    /// Allowed values of an Enum parameter; empty for every other type.
+    pub variants: Vec<String>,
+}
+
+impl ParameterSpec {
+    /// Checks the declaration with `check_declaration` and returns its bounds parsed.
+    pub fn parsed_bounds(&self) -> Result<Option<Bounds>, ParameterDeclarationError> {
+        let bounds = self
+            .bounds
+            .as_deref()
+            .map(Bounds::parse)
+            .transpose()
+            .map_err(ParameterDeclarationError::InvalidBounds)?;
+        check_declaration(
+            &self.value_type,
+            self.default.as_ref(),
+            &self.variants,
+            bounds.as_ref(),
+        )?;
+        Ok(bounds)
+    }
+}
+
+/// Why a declared parameter is rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParameterDeclarationError {
+    EnumVariantsRequired,
+    VariantsOnNonEnum,
+    UnknownDefaultVariant(String),
+    InvalidBounds(BoundsError),
+    DefaultOutOfBounds,
+}
+
+/// Enum parameters list their variants and nothing else does; declared bounds must
+/// fit the parameter's type. A default must satisfy both.
+pub fn check_declaration(
+    ty: &ParameterType,
+    default: Option<&ParameterValue>,
+    variants: &[String],
+    bounds: Option<&Bounds>,
+) -> Result<(), ParameterDeclarationError> {
+    let is_enum = *ty == ParameterType::Enum;
+    if is_enum && variants.is_empty() {
+        return Err(ParameterDeclarationError::EnumVariantsRequired);
+    }
+    if !is_enum && !variants.is_empty() {
+        return Err(ParameterDeclarationError::VariantsOnNonEnum);
+    }
+    if let Some(ParameterValue::Enum(variant)) = default {
+        if !variants.contains(variant) {
+            return Err(ParameterDeclarationError::UnknownDefaultVariant(
+                variant.clone(),
+            ));
+        }
+    }
+
+    let Some(bounds) = bounds else {
+        return Ok(());
+    };
+    if ty.bounded_kind() != Some(bounds.kind()) {
+        return Err(ParameterDeclarationError::InvalidBounds(
+            BoundsError::NotApplicable,
+        ));
+    }
+    if default.is_some_and(|value| !value.bounded().is_some_and(|v| bounds.admits(v))) {
+        return Err(ParameterDeclarationError::DefaultOutOfBounds);
+    }
+    Ok(())
+}
@@ -3,6 +3,11 @@ pub enum ValueType {
     Number,
     Series,
     Bool,
+    String,
+    /// A type variable, named by a single letter such as `T`. Every port a manifest
+    /// types with the same variable takes one concrete type per node, bound from what
+    /// is wired into its inputs.
+    Var(char),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -11,10 +16,15 @@ pub enum PrimitiveKind {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum Value {
     Number(f64),
     Series(Vec<f64>),
     Bool(bool),
+    String(String),
+    /// Values of a `Cardinality::Multiple` input, in edge order.
+    List(Vec<Value>),
 }
 
 impl Value {
@@ -23,6 +33,9 @@ impl Value {
             Value::Number(_) => ValueType::Number,
             Value::Series(_) => ValueType::Series,
             Value::Bool(_) => ValueType::Bool,
+            Value::String(_) => ValueType::String,
+            // A list is typed by its items; the runtime never delivers an empty one.
+            Value::List(items) => items.first().map_or(ValueType::Series, Value::value_type),
         }
     }
 
@@ -46,4 +59,18 @@ impl Value {
             _ => None,
         }
     }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Value::List(items) => Some(items),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::{number_input, number_parameter};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::approx_eq_manifest;
+
+/// True when `a` and `b` differ by at most `epsilon`.
+pub struct ApproxEq {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl ApproxEq {
+    pub fn new() -> Self {
+        Self {
+            manifest: approx_eq_manifest(),
+        }
+    }
+}
+
+impl Default for ApproxEq {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for ApproxEq {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = number_input(inputs, "a")?;
+        let b = number_input(inputs, "b")?;
+        let epsilon = number_parameter(parameters, "epsilon")?;
+
+        Ok(HashMap::from([(
+            "result".to_string(),
+            Value::Bool((a - b).abs() <= epsilon),
+        )]))
+    }
+}
@@ -0,0 +1,47 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, ParameterValue, StateSpec,
+};
+
+pub fn approx_eq_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "approx_eq".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![
+            InputSpec {
+                name: "a".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "b".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+        ],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::Bool,
+        }],
+        parameters: vec![ParameterSpec {
+            name: "epsilon".to_string(),
+            value_type: ParameterType::Number,
+            default: Some(ParameterValue::Number(1e-9)),
+            bounds: Some("[0, )".to_string()),
+            variants: Vec::new(),
+        }],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
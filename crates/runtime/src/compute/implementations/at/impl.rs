@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{
+    integer_parameter, number_result, series_input,
+};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::at_manifest;
+
+pub struct At {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl At {
+    pub fn new() -> Self {
+        Self {
+            manifest: at_manifest(),
+        }
+    }
+}
+
+impl Default for At {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for At {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        // A negative index counts back from the end: -1 is the last value.
+        let index = integer_parameter(parameters, "index")?;
+        let position = if index < 0 {
+            series.len() as i64 + index
+        } else {
+            index
+        };
+        let value = usize::try_from(position)
+            .ok()
+            .and_then(|i| series.get(i))
+            .ok_or_else(|| ComputeError::InvalidInput {
+                name: "series".to_string(),
+                reason: format!("no value at index {} of {}", index, series.len()),
+            })?;
+        Ok(number_result(*value))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, StateSpec,
+};
+
+pub fn at_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "at".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![InputSpec {
+            name: "series".to_string(),
+            value_type: ValueType::Series,
+            required: true,
+            cardinality: Cardinality::Single,
+        }],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::Number,
+        }],
+        parameters: vec![ParameterSpec {
+            name: "index".to_string(),
+            value_type: ParameterType::Int,
+            default: None,
+            bounds: None,
+            variants: Vec::new(),
+        }],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::between_manifest;
+
+/// The `bounds` parameter's `inclusive` variant selects the closed range
+/// `[low, high]`, `exclusive` the open range `(low, high)`.
+pub struct Between {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Between {
+    pub fn new() -> Self {
+        Self {
+            manifest: between_manifest(),
+        }
+    }
+}
+
+impl Default for Between {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Between {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let value = number_input(inputs, "value")?;
+        let low = number_input(inputs, "low")?;
+        let high = number_input(inputs, "high")?;
+        let inclusive = match parameters.get("bounds").and_then(|v| v.as_str()) {
+            Some("inclusive") => true,
+            Some("exclusive") => false,
+            _ => {
+                return Err(ComputeError::InvalidParameter {
+                    name: "bounds".to_string(),
+                    reason: "expected \"inclusive\" or \"exclusive\"".to_string(),
+                })
+            }
+        };
+
+        // An inverted range contains nothing.
+        let result = if inclusive {
+            low <= value && value <= high
+        } else {
+            low < value && value < high
+        };
+
+        Ok(HashMap::from([("result".to_string(), Value::Bool(result))]))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, ParameterValue, StateSpec,
+};
+
+pub fn between_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "between".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![
+            InputSpec {
+                name: "value".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "low".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "high".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+        ],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::Bool,
+        }],
+        parameters: vec![ParameterSpec {
+            name: "bounds".to_string(),
+            value_type: ParameterType::Enum,
+            default: Some(ParameterValue::Enum("inclusive".to_string())),
+            bounds: None,
+            variants: vec!["inclusive".to_string(), "exclusive".to_string()],
+        }],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::{number_input, precision_scale};
+use crate::compute::implementations::series_support::number_result;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::ceil_manifest;
+
+pub struct Ceil {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Ceil {
+    pub fn new() -> Self {
+        Self {
+            manifest: ceil_manifest(),
+        }
+    }
+}
+
+impl Default for Ceil {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Ceil {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let value = number_input(inputs, "value")?;
+        let scale = precision_scale(parameters)?;
+
+        Ok(number_result((value * scale).ceil() / scale))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, ParameterValue, StateSpec,
+};
+
+pub fn ceil_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "ceil".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![InputSpec {
+            name: "value".to_string(),
+            value_type: ValueType::Number,
+            required: true,
+            cardinality: Cardinality::Single,
+        }],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::Number,
+        }],
+        parameters: vec![ParameterSpec {
+            name: "precision".to_string(),
+            value_type: ParameterType::Int,
+            default: Some(ParameterValue::Int(0)),
+            bounds: Some("[-15, 15]".to_string()),
+            variants: Vec::new(),
+        }],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::clamp_manifest;
+
+pub struct Clamp {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Clamp {
+    pub fn new() -> Self {
+        Self {
+            manifest: clamp_manifest(),
+        }
+    }
+}
+
+impl Default for Clamp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Clamp {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let number = |name: &str| {
+            inputs
+                .get(name)
+                .and_then(|v| v.as_number())
+                .ok_or_else(|| ComputeError::MissingInput {
+                    name: name.to_string(),
+                })
+        };
+        let (value, min, max) = (number("value")?, number("min")?, number("max")?);
+        // Bounds arrive as inputs, so an inverted or NaN pair is a runtime failure here.
+        if min > max || min.is_nan() || max.is_nan() {
+            return Err(ComputeError::InvalidInput {
+                name: "min".to_string(),
+                reason: format!("[{}, {}] is not a valid range", min, max),
+            });
+        }
+
+        Ok(HashMap::from([(
+            "result".to_string(),
+            Value::Number(value.clamp(min, max)),
+        )]))
+    }
+}
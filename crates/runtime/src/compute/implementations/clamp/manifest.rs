@@ -0,0 +1,46 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
+};
+
+pub fn clamp_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "clamp".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![
+            InputSpec {
+                name: "value".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "min".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "max".to_string(),
+                value_type: ValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+        ],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::Number,
+        }],
+        parameters: vec![],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
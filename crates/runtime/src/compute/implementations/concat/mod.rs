@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::concat_manifest;
+pub use r#impl::Concat;
@@ -1,7 +1,7 @@
 use crate::common::{PrimitiveKind, ValueType};
 use crate::compute::{
-    Cadence, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, ParameterSpec,
-    StateSpec,
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, StateSpec,
 };
 
 pub fn const_bool_manifest() -> ComputePrimitiveManifest {
@@ -13,6 +13,7 @@ pub fn const_bool_manifest() -> ComputePrimitiveManifest {
             name: "unit".to_string(),
             value_type: ValueType::Bool,
             required: false,
+            cardinality: Cardinality::Single,
         }],
         outputs: vec![OutputSpec {
             name: "value".to_string(),
@@ -20,8 +21,10 @@ pub fn const_bool_manifest() -> ComputePrimitiveManifest {
         }],
         parameters: vec![ParameterSpec {
             name: "value".to_string(),
-            value_type: ValueType::Bool,
+            value_type: ParameterType::Bool,
             default: None,
+            bounds: None,
+            variants: Vec::new(),
         }],
         execution: ExecutionSpec {
             deterministic: true,
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{series_input, series_result};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::cumsum_manifest;
+
+pub struct Cumsum {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Cumsum {
+    pub fn new() -> Self {
+        Self {
+            manifest: cumsum_manifest(),
+        }
+    }
+}
+
+impl Default for Cumsum {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Cumsum {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        let values = series
+            .iter()
+            .scan(0.0, |total, x| {
+                *total += x;
+                Some(*total)
+            })
+            .collect();
+        Ok(series_result(values))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
+};
+
+pub fn cumsum_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "cumsum".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![InputSpec {
+            name: "series".to_string(),
+            value_type: ValueType::Series,
+            required: true,
+            cardinality: Cardinality::Single,
+        }],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::Series,
+        }],
+        parameters: vec![],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{
+    count_parameter, series_input, series_result,
+};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::diff_manifest;
+
+pub struct Diff {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Diff {
+    pub fn new() -> Self {
+        Self {
+            manifest: diff_manifest(),
+        }
+    }
+}
+
+impl Default for Diff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Diff {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        // series[i] - series[i - periods], for every i that has a predecessor.
+        let periods = count_parameter(parameters, "periods", 1)?;
+        let values = series
+            .iter()
+            .skip(periods)
+            .zip(series)
+            .map(|(current, previous)| current - previous)
+            .collect();
+        Ok(series_result(values))
+    }
+}
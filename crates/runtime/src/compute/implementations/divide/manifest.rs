@@ -1,6 +1,6 @@
 use crate::common::{PrimitiveKind, ValueType};
 use crate::compute::{
-    Cadence, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
 };
 
 pub fn divide_manifest() -> ComputePrimitiveManifest {
@@ -13,11 +13,13 @@ pub fn divide_manifest() -> ComputePrimitiveManifest {
                 name: "a".to_string(),
                 value_type: ValueType::Number,
                 required: true,
+                cardinality: Cardinality::Single,
             },
             InputSpec {
                 name: "b".to_string(),
                 value_type: ValueType::Number,
                 required: true,
+                cardinality: Cardinality::Single,
             },
         ],
         outputs: vec![OutputSpec {
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::implementations::series_support::number_result;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::exp_manifest;
+
+pub struct Exp {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Exp {
+    pub fn new() -> Self {
+        Self {
+            manifest: exp_manifest(),
+        }
+    }
+}
+
+impl Default for Exp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Exp {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let value = number_input(inputs, "value")?;
+
+        Ok(number_result(value.exp()))
+    }
+}
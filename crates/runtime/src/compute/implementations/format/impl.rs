@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::format_manifest;
+
+/// Fills `template` from the string inputs `a` to `d`: `{a}` is replaced by input `a`,
+/// and `{{` and `}}` stand for literal braces. The parameter's bounds reject a malformed
+/// template or an unknown placeholder at validation; a template naming an unwired input
+/// fails the node.
+pub struct Format {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Format {
+    pub fn new() -> Self {
+        Self {
+            manifest: format_manifest(),
+        }
+    }
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Format {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let template = parameters
+            .get("template")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| ComputeError::InvalidParameter {
+                name: "template".to_string(),
+                reason: "missing required string parameter".to_string(),
+            })?;
+        let invalid = |reason: String| ComputeError::InvalidParameter {
+            name: "template".to_string(),
+            reason,
+        };
+
+        let mut result = String::with_capacity(template.len());
+        let mut chars = template.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    result.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    result.push('}');
+                }
+                '{' => {
+                    let rest = chars.as_str();
+                    let end = rest
+                        .find('}')
+                        .ok_or_else(|| invalid("unclosed '{'".to_string()))?;
+                    let name = &rest[..end];
+                    if !self.manifest.inputs.iter().any(|input| input.name == name) {
+                        return Err(invalid(format!("unknown placeholder '{{{}}}'", name)));
+                    }
+                    let value = inputs.get(name).and_then(|v| v.as_str()).ok_or_else(|| {
+                        ComputeError::MissingInput {
+                            name: name.to_string(),
+                        }
+                    })?;
+                    result.push_str(value);
+                    chars = rest[end + 1..].chars();
+                }
+                '}' => return Err(invalid("unmatched '}'".to_string())),
+                c => result.push(c),
+            }
+        }
+
+        Ok(HashMap::from([(
+            "result".to_string(),
+            Value::String(result),
+        )]))
+    }
+}
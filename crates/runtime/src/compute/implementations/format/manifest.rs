@@ -0,0 +1,60 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, StateSpec,
+};
+
+pub fn format_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "format".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![
+            InputSpec {
+                name: "a".to_string(),
+                value_type: ValueType::String,
+                required: false,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "b".to_string(),
+                value_type: ValueType::String,
+                required: false,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "c".to_string(),
+                value_type: ValueType::String,
+                required: false,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "d".to_string(),
+                value_type: ValueType::String,
+                required: false,
+                cardinality: Cardinality::Single,
+            },
+        ],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::String,
+        }],
+        parameters: vec![ParameterSpec {
+            name: "template".to_string(),
+            value_type: ParameterType::String,
+            default: None,
+            // Literal text, `{{`, `}}` and placeholders naming the inputs `a` to `d`.
+            bounds: Some(r"matches /^([^{}]|\{\{|\}\}|\{[a-d]\})*$/".to_string()),
+            variants: Vec::new(),
+        }],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::gte_manifest;
+
+pub struct Gte {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Gte {
+    pub fn new() -> Self {
+        Self {
+            manifest: gte_manifest(),
+        }
+    }
+}
+
+impl Default for Gte {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Gte {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = number_input(inputs, "a")?;
+        let b = number_input(inputs, "b")?;
+
+        Ok(HashMap::from([("result".to_string(), Value::Bool(a >= b))]))
+    }
+}
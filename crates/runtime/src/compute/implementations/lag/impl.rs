@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{
+    count_parameter, series_input, series_result,
+};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::lag_manifest;
+
+pub struct Lag {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Lag {
+    pub fn new() -> Self {
+        Self {
+            manifest: lag_manifest(),
+        }
+    }
+}
+
+impl Default for Lag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Lag {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        // The series as it stood `periods` values ago.
+        let periods = count_parameter(parameters, "periods", 0)?;
+        let keep = series.len().saturating_sub(periods);
+        Ok(series_result(series[..keep].to_vec()))
+    }
+}
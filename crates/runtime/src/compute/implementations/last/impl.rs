@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{number_result, series_input};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::last_manifest;
+
+pub struct Last {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Last {
+    pub fn new() -> Self {
+        Self {
+            manifest: last_manifest(),
+        }
+    }
+}
+
+impl Default for Last {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Last {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        let value = series.last().ok_or_else(|| ComputeError::InvalidInput {
+            name: "series".to_string(),
+            reason: "series is empty".to_string(),
+        })?;
+        Ok(number_result(*value))
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{number_result, series_input};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::len_manifest;
+
+pub struct Len {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Len {
+    pub fn new() -> Self {
+        Self {
+            manifest: len_manifest(),
+        }
+    }
+}
+
+impl Default for Len {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Len {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        Ok(number_result(series.len() as f64))
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::implementations::series_support::number_result;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::ln_manifest;
+
+pub struct Ln {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Ln {
+    pub fn new() -> Self {
+        Self {
+            manifest: ln_manifest(),
+        }
+    }
+}
+
+impl Default for Ln {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Ln {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let value = number_input(inputs, "value")?;
+
+        Ok(number_result(value.ln()))
+    }
+}
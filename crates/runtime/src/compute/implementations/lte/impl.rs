@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::lte_manifest;
+
+pub struct Lte {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Lte {
+    pub fn new() -> Self {
+        Self {
+            manifest: lte_manifest(),
+        }
+    }
+}
+
+impl Default for Lte {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Lte {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = number_input(inputs, "a")?;
+        let b = number_input(inputs, "b")?;
+
+        Ok(HashMap::from([("result".to_string(), Value::Bool(a <= b))]))
+    }
+}
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::implementations::series_support::number_result;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::max_manifest;
+
+pub struct Max {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Max {
+    pub fn new() -> Self {
+        Self {
+            manifest: max_manifest(),
+        }
+    }
+}
+
+impl Default for Max {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Max {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = number_input(inputs, "a")?;
+        let b = number_input(inputs, "b")?;
+
+        Ok(number_result(a.max(b)))
+    }
+}
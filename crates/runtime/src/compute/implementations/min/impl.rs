@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::implementations::series_support::number_result;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::min_manifest;
+
+pub struct Min {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Min {
+    pub fn new() -> Self {
+        Self {
+            manifest: min_manifest(),
+        }
+    }
+}
+
+impl Default for Min {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Min {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = number_input(inputs, "a")?;
+        let b = number_input(inputs, "b")?;
+
+        Ok(number_result(a.min(b)))
+    }
+}
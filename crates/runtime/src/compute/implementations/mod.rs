@@ -1,31 +1,109 @@
+pub mod abs;
 pub mod add;
 pub mod and;
+pub mod approx_eq;
+pub mod at;
+pub mod between;
+pub mod ceil;
+pub mod clamp;
+pub mod concat;
 pub mod const_bool;
 pub mod const_number;
+pub mod cumsum;
+pub mod diff;
 pub mod divide;
 pub mod eq;
+pub mod exp;
+pub mod floor;
+pub mod format;
 pub mod gt;
+pub mod gte;
+pub mod implies;
+pub mod lag;
+pub mod last;
+pub mod len;
+pub mod ln;
 pub mod lt;
+pub mod lte;
+pub mod max;
+pub mod min;
+pub mod modulo;
 pub mod multiply;
+pub mod nand;
 pub mod negate;
 pub mod neq;
 pub mod not;
 pub mod or;
+pub mod pow;
+pub mod rolling_max;
+pub mod rolling_mean;
+pub mod rolling_min;
+pub mod rolling_stdev;
+pub mod rolling_sum;
+pub mod round;
+pub mod safe_divide;
+mod scalar_support;
 pub mod select;
+mod series_support;
+pub mod sign;
+pub mod slice;
+pub mod sqrt;
+pub mod str_contains;
+pub mod str_eq;
 pub mod subtract;
+pub mod to_string;
+pub mod xor;
 
+pub use abs::Abs;
 pub use add::Add;
 pub use and::And;
+pub use approx_eq::ApproxEq;
+pub use at::At;
+pub use between::Between;
+pub use ceil::Ceil;
+pub use clamp::Clamp;
+pub use concat::Concat;
 pub use const_bool::ConstBool;
 pub use const_number::ConstNumber;
+pub use cumsum::Cumsum;
+pub use diff::Diff;
 pub use divide::Divide;
 pub use eq::Eq;
+pub use exp::Exp;
+pub use floor::Floor;
+pub use format::Format;
 pub use gt::Gt;
+pub use gte::Gte;
+pub use implies::Implies;
+pub use lag::Lag;
+pub use last::Last;
+pub use len::Len;
+pub use ln::Ln;
 pub use lt::Lt;
+pub use lte::Lte;
+pub use max::Max;
+pub use min::Min;
+pub use modulo::Modulo;
 pub use multiply::Multiply;
+pub use nand::Nand;
 pub use negate::Negate;
 pub use neq::Neq;
 pub use not::Not;
 pub use or::Or;
+pub use pow::Pow;
+pub use rolling_max::RollingMax;
+pub use rolling_mean::RollingMean;
+pub use rolling_min::RollingMin;
+pub use rolling_stdev::RollingStdev;
+pub use rolling_sum::RollingSum;
+pub use round::Round;
+pub use safe_divide::SafeDivide;
 pub use select::Select;
+pub use sign::Sign;
+pub use slice::Slice;
+pub use sqrt::Sqrt;
+pub use str_contains::StrContains;
+pub use str_eq::StrEq;
 pub use subtract::Subtract;
+pub use to_string::NumberToString;
+pub use xor::Xor;
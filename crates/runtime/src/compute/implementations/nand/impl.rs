@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::bool_input;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::nand_manifest;
+
+pub struct Nand {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Nand {
+    pub fn new() -> Self {
+        Self {
+            manifest: nand_manifest(),
+        }
+    }
+}
+
+impl Default for Nand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Nand {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = bool_input(inputs, "a")?;
+        let b = bool_input(inputs, "b")?;
+
+        Ok(HashMap::from([(
+            "result".to_string(),
+            Value::Bool(!(a && b)),
+        )]))
+    }
+}
@@ -1,6 +1,6 @@
 use crate::common::{PrimitiveKind, ValueType};
 use crate::compute::{
-    Cadence, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
 };
 
 pub fn negate_manifest() -> ComputePrimitiveManifest {
@@ -12,6 +12,7 @@ pub fn negate_manifest() -> ComputePrimitiveManifest {
             name: "value".to_string(),
             value_type: ValueType::Number,
             required: true,
+            cardinality: Cardinality::Single,
         }],
         outputs: vec![OutputSpec {
             name: "result".to_string(),
@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::rolling_max_manifest;
+pub use r#impl::RollingMax;
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{
+    count_parameter, rolling, series_input, series_result,
+};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::rolling_stdev_manifest;
+
+pub struct RollingStdev {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl RollingStdev {
+    pub fn new() -> Self {
+        Self {
+            manifest: rolling_stdev_manifest(),
+        }
+    }
+}
+
+impl Default for RollingStdev {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for RollingStdev {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        // Sample standard deviation (n - 1 denominator), hence a window of at least 2.
+        let window = count_parameter(parameters, "window", 2)?;
+        Ok(series_result(rolling(series, window, |w| {
+            let n = w.len() as f64;
+            let mean = w.iter().sum::<f64>() / n;
+            let squares: f64 = w.iter().map(|x| (x - mean).powi(2)).sum();
+            (squares / (n - 1.0)).sqrt()
+        })))
+    }
+}
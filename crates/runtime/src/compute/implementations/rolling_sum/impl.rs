@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{
+    count_parameter, rolling, series_input, series_result,
+};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::rolling_sum_manifest;
+
+pub struct RollingSum {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl RollingSum {
+    pub fn new() -> Self {
+        Self {
+            manifest: rolling_sum_manifest(),
+        }
+    }
+}
+
+impl Default for RollingSum {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for RollingSum {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        let window = count_parameter(parameters, "window", 1)?;
+        Ok(series_result(rolling(series, window, |w| w.iter().sum())))
+    }
+}
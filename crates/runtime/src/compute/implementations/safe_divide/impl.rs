@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::{number_input, number_parameter};
+use crate::compute::implementations::series_support::number_result;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::safe_divide_manifest;
+
+/// `a / b`, or `fallback` wherever that quotient is not finite: a zero divisor,
+/// overflow, or a non-finite input.
+pub struct SafeDivide {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl SafeDivide {
+    pub fn new() -> Self {
+        Self {
+            manifest: safe_divide_manifest(),
+        }
+    }
+}
+
+impl Default for SafeDivide {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for SafeDivide {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = number_input(inputs, "a")?;
+        let b = number_input(inputs, "b")?;
+        let fallback = number_parameter(parameters, "fallback")?;
+
+        let quotient = a / b;
+        let result = if quotient.is_finite() {
+            quotient
+        } else {
+            fallback
+        };
+
+        Ok(number_result(result))
+    }
+}
@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::safe_divide_manifest;
+pub use r#impl::SafeDivide;
@@ -0,0 +1,69 @@
+//! Input and parameter handling shared by the scalar math, comparison and logic
+//! primitives.
+//!
+//! An absent input fails with `ComputeError::MissingInput` and one of the wrong
+//! type with `ComputeError::InvalidInput`. Validation normally rules out both, so
+//! these checks guard direct calls.
+
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::integer_parameter;
+use crate::compute::ComputeError;
+
+pub(super) fn number_input(
+    inputs: &HashMap<String, Value>,
+    name: &str,
+) -> Result<f64, ComputeError> {
+    match inputs.get(name) {
+        Some(value) => value.as_number().ok_or_else(|| ComputeError::InvalidInput {
+            name: name.to_string(),
+            reason: "expected a number".to_string(),
+        }),
+        None => Err(ComputeError::MissingInput {
+            name: name.to_string(),
+        }),
+    }
+}
+
+pub(super) fn bool_input(
+    inputs: &HashMap<String, Value>,
+    name: &str,
+) -> Result<bool, ComputeError> {
+    match inputs.get(name) {
+        Some(value) => value.as_bool().ok_or_else(|| ComputeError::InvalidInput {
+            name: name.to_string(),
+            reason: "expected a bool".to_string(),
+        }),
+        None => Err(ComputeError::MissingInput {
+            name: name.to_string(),
+        }),
+    }
+}
+
+pub(super) fn number_parameter(
+    parameters: &HashMap<String, Value>,
+    name: &str,
+) -> Result<f64, ComputeError> {
+    parameters
+        .get(name)
+        .and_then(Value::as_number)
+        .ok_or_else(|| ComputeError::InvalidParameter {
+            name: name.to_string(),
+            reason: "missing numeric parameter".to_string(),
+        })
+}
+
+/// The scale `10^precision` used by `round`, `floor` and `ceil`: `precision` digits
+/// are kept after the decimal point, and a negative precision rounds to tens,
+/// hundreds and so on.
+pub(super) fn precision_scale(parameters: &HashMap<String, Value>) -> Result<f64, ComputeError> {
+    let precision = integer_parameter(parameters, "precision")?;
+    if !(-15..=15).contains(&precision) {
+        return Err(ComputeError::InvalidParameter {
+            name: "precision".to_string(),
+            reason: "must be within [-15, 15]".to_string(),
+        });
+    }
+    Ok(10f64.powi(precision as i32))
+}
@@ -40,15 +40,13 @@ impl ComputePrimitive for Select {
             .expect("missing required bool input 'cond'");
         let when_true = inputs
             .get("when_true")
-            .and_then(|v| v.as_number())
-            .expect("missing required numeric input 'when_true'");
+            .expect("missing required input 'when_true'");
         let when_false = inputs
             .get("when_false")
-            .and_then(|v| v.as_number())
-            .expect("missing required numeric input 'when_false'");
+            .expect("missing required input 'when_false'");
 
         let result = if cond { when_true } else { when_false };
 
-        HashMap::from([("result".to_string(), Value::Number(result))])
+        HashMap::from([("result".to_string(), result.clone())])
     }
 }
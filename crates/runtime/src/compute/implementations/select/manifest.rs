@@ -1,9 +1,10 @@
 use crate::common::{PrimitiveKind, ValueType};
 use crate::compute::{
-    Cadence, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec, StateSpec,
 };
 
-// Output is numeric; both branches must be numeric to avoid implicit coercion.
+// Both branches and the output share the type variable `T`, bound per node by what is
+// wired into the branches, so select never coerces.
 pub fn select_manifest() -> ComputePrimitiveManifest {
     ComputePrimitiveManifest {
         id: "select".to_string(),
@@ -14,21 +15,24 @@ pub fn select_manifest() -> ComputePrimitiveManifest {
                 name: "cond".to_string(),
                 value_type: ValueType::Bool,
                 required: true,
+                cardinality: Cardinality::Single,
             },
             InputSpec {
                 name: "when_true".to_string(),
-                value_type: ValueType::Number,
+                value_type: ValueType::Var('T'),
                 required: true,
+                cardinality: Cardinality::Single,
             },
             InputSpec {
                 name: "when_false".to_string(),
-                value_type: ValueType::Number,
+                value_type: ValueType::Var('T'),
                 required: true,
+                cardinality: Cardinality::Single,
             },
         ],
         outputs: vec![OutputSpec {
             name: "result".to_string(),
-            value_type: ValueType::Number,
+            value_type: ValueType::Var('T'),
         }],
         parameters: vec![],
         execution: ExecutionSpec {
@@ -0,0 +1,78 @@
+//! Input and parameter handling shared by the Series primitives.
+//!
+//! Primitives that map a series to a series (`slice`, `lag`, `rolling_*`, `cumsum`,
+//! `diff`) emit only fully defined values: an empty or short input yields a shorter,
+//! possibly empty, series, never padding or an error. Primitives that extract one
+//! number (`last`, `at`) fail with `ComputeError::InvalidInput` when the series has no
+//! value at the requested position. `len` of an empty series is 0.
+
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::ComputeError;
+
+pub(super) fn series_input<'a>(
+    inputs: &'a HashMap<String, Value>,
+    name: &str,
+) -> Result<&'a [f64], ComputeError> {
+    match inputs.get(name) {
+        Some(Value::Series(series)) => Ok(series),
+        Some(_) => Err(ComputeError::InvalidInput {
+            name: name.to_string(),
+            reason: "expected a series".to_string(),
+        }),
+        None => Err(ComputeError::MissingInput {
+            name: name.to_string(),
+        }),
+    }
+}
+
+/// An Int parameter, which reaches the primitive as a Number; negative values are
+/// allowed. Validation rejects a non-integral value before execution, so the check
+/// here only guards direct calls.
+pub(super) fn integer_parameter(
+    parameters: &HashMap<String, Value>,
+    name: &str,
+) -> Result<i64, ComputeError> {
+    let invalid = |reason: &str| ComputeError::InvalidParameter {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    };
+    let n = parameters
+        .get(name)
+        .and_then(Value::as_number)
+        .ok_or_else(|| invalid("missing numeric parameter"))?;
+    if n.fract() != 0.0 || !n.is_finite() {
+        return Err(invalid("expected an integer"));
+    }
+    Ok(n as i64)
+}
+
+/// An Int parameter of at least `min`, such as a window size.
+pub(super) fn count_parameter(
+    parameters: &HashMap<String, Value>,
+    name: &str,
+    min: usize,
+) -> Result<usize, ComputeError> {
+    let n = integer_parameter(parameters, name)?;
+    if n < min as i64 {
+        return Err(ComputeError::InvalidParameter {
+            name: name.to_string(),
+            reason: format!("must be at least {}", min),
+        });
+    }
+    Ok(n as usize)
+}
+
+/// One value per complete window, so a series shorter than `window` yields none.
+pub(super) fn rolling(series: &[f64], window: usize, f: impl Fn(&[f64]) -> f64) -> Vec<f64> {
+    series.windows(window).map(f).collect()
+}
+
+pub(super) fn series_result(values: Vec<f64>) -> HashMap<String, Value> {
+    HashMap::from([("result".to_string(), Value::Series(values))])
+}
+
+pub(super) fn number_result(value: f64) -> HashMap<String, Value> {
+    HashMap::from([("result".to_string(), Value::Number(value))])
+}
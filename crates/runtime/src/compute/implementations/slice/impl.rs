@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::series_support::{
+    count_parameter, integer_parameter, series_input, series_result,
+};
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::slice_manifest;
+
+pub struct Slice {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Slice {
+    pub fn new() -> Self {
+        Self {
+            manifest: slice_manifest(),
+        }
+    }
+}
+
+impl Default for Slice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Slice {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let series = series_input(inputs, "series")?;
+        // A negative start counts back from the end; the slice is clamped to the series.
+        let start = integer_parameter(parameters, "start")?;
+        let count = count_parameter(parameters, "count", 0)?;
+        let len = series.len() as i64;
+        let from = if start < 0 { len + start } else { start };
+        let from = from.clamp(0, len) as usize;
+        let to = from.saturating_add(count).min(series.len());
+        Ok(series_result(series[from..to].to_vec()))
+    }
+}
@@ -0,0 +1,48 @@
+use crate::common::{PrimitiveKind, ValueType};
+use crate::compute::{
+    Cadence, Cardinality, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
+    ParameterSpec, ParameterType, ParameterValue, StateSpec,
+};
+
+pub fn slice_manifest() -> ComputePrimitiveManifest {
+    ComputePrimitiveManifest {
+        id: "slice".to_string(),
+        version: "0.1.0".to_string(),
+        kind: PrimitiveKind::Compute,
+        inputs: vec![InputSpec {
+            name: "series".to_string(),
+            value_type: ValueType::Series,
+            required: true,
+            cardinality: Cardinality::Single,
+        }],
+        outputs: vec![OutputSpec {
+            name: "result".to_string(),
+            value_type: ValueType::Series,
+        }],
+        parameters: vec![
+            ParameterSpec {
+                name: "start".to_string(),
+                value_type: ParameterType::Int,
+                default: Some(ParameterValue::Int(0)),
+                bounds: None,
+                variants: Vec::new(),
+            },
+            ParameterSpec {
+                name: "count".to_string(),
+                value_type: ParameterType::Int,
+                default: None,
+                bounds: Some("[0, )".to_string()),
+                variants: Vec::new(),
+            },
+        ],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            stateful: false,
+            rolling_window: None,
+        },
+        side_effects: false,
+    }
+}
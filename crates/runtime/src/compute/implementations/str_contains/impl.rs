@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::{ComputePrimitive, ComputePrimitiveManifest, PrimitiveState};
+
+use super::manifest::str_contains_manifest;
+
+pub struct StrContains {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl StrContains {
+    pub fn new() -> Self {
+        Self {
+            manifest: str_contains_manifest(),
+        }
+    }
+}
+
+impl Default for StrContains {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComputePrimitive for StrContains {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> HashMap<String, Value> {
+        let value = inputs
+            .get("value")
+            .and_then(|v| v.as_str())
+            .expect("missing required string input 'value'");
+        let substring = inputs
+            .get("substring")
+            .and_then(|v| v.as_str())
+            .expect("missing required string input 'substring'");
+
+        HashMap::from([("result".to_string(), Value::Bool(value.contains(substring)))])
+    }
+}
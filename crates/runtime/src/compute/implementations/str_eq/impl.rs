@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::{ComputePrimitive, ComputePrimitiveManifest, PrimitiveState};
+
+use super::manifest::str_eq_manifest;
+
+pub struct StrEq {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl StrEq {
+    pub fn new() -> Self {
+        Self {
+            manifest: str_eq_manifest(),
+        }
+    }
+}
+
+impl Default for StrEq {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComputePrimitive for StrEq {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> HashMap<String, Value> {
+        let a = inputs
+            .get("a")
+            .and_then(|v| v.as_str())
+            .expect("missing required string input 'a'");
+        let b = inputs
+            .get("b")
+            .and_then(|v| v.as_str())
+            .expect("missing required string input 'b'");
+
+        HashMap::from([("result".to_string(), Value::Bool(a == b))])
+    }
+}
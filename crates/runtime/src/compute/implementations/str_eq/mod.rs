@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::str_eq_manifest;
+pub use r#impl::StrEq;
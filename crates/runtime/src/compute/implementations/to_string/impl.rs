@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::number_input;
+use crate::compute::implementations::series_support::count_parameter;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::to_string_manifest;
+
+/// Formats a number with a fixed count of digits after the decimal point.
+pub struct NumberToString {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl NumberToString {
+    pub fn new() -> Self {
+        Self {
+            manifest: to_string_manifest(),
+        }
+    }
+}
+
+impl Default for NumberToString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for NumberToString {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let value = number_input(inputs, "value")?;
+        let precision = count_parameter(parameters, "precision", 0)?;
+
+        Ok(HashMap::from([(
+            "result".to_string(),
+            Value::String(format!("{:.*}", precision, value)),
+        )]))
+    }
+}
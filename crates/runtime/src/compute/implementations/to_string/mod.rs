@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::to_string_manifest;
+pub use r#impl::NumberToString;
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::compute::implementations::scalar_support::bool_input;
+use crate::compute::{
+    ComputeError, ComputePrimitiveManifest, FallibleComputePrimitive, PrimitiveState,
+};
+
+use super::manifest::xor_manifest;
+
+pub struct Xor {
+    manifest: ComputePrimitiveManifest,
+}
+
+impl Xor {
+    pub fn new() -> Self {
+        Self {
+            manifest: xor_manifest(),
+        }
+    }
+}
+
+impl Default for Xor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleComputePrimitive for Xor {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        _parameters: &HashMap<String, Value>,
+        _state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        let a = bool_input(inputs, "a")?;
+        let b = bool_input(inputs, "b")?;
+
+        Ok(HashMap::from([("result".to_string(), Value::Bool(a != b))]))
+    }
+}
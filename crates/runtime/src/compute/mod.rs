@@ -12,11 +12,19 @@ pub enum Cadence {
     Event,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cardinality {
+    Single,
+    /// Any number of edges; the primitive receives `Value::List` in edge order.
+    Multiple,
+}
+
 #[derive(Debug, Clone)]
 pub struct InputSpec {
     pub name: String,
     pub value_type: ValueType,
     pub required: bool,
+    pub cardinality: Cardinality,
 }
 
 #[derive(Debug, Clone)]
@@ -25,13 +33,6 @@ pub struct OutputSpec {
     pub value_type: ValueType,
 }
 
-#[derive(Debug, Clone)]
-pub struct ParameterSpec {
-    pub name: String,
-    pub value_type: ValueType,
-    pub default: Option<Value>,
-}
-
 #[derive(Debug, Clone)]
 pub struct ExecutionSpec {
     pub deterministic: bool,
@@ -44,7 +45,9 @@ pub struct StateSpec {
     pub rolling_window: Option<usize>,
 }
 
-#[derive(Debug, Clone, Default)]
+/// Declared node state for a Compute primitive whose manifest sets `stateful: true`.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrimitiveState {
     pub data: HashMap<String, Value>,
 }
@@ -62,7 +65,10 @@ pub struct ComputePrimitiveManifest {
     pub side_effects: bool,
 }
 
-pub trait ComputePrimitive {
+/// `Send + Sync` so `ParallelExecutor` can evaluate independent compute nodes on
+/// worker threads. The bound came in with `ParallelExecutor`; the worker pool only
+/// relies on it.
+pub trait ComputePrimitive: Send + Sync {
     fn manifest(&self) -> &ComputePrimitiveManifest;
 
     fn compute(
@@ -73,11 +79,55 @@ pub trait ComputePrimitive {
     ) -> HashMap<String, Value>;
 }
 
+/// Failure reported by a compute primitive in place of a panic.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComputeError {
+    MissingInput { name: String },
+    InvalidInput { name: String, reason: String },
+    InvalidParameter { name: String, reason: String },
+    Failed(String),
+}
+
+/// Result-returning form of `ComputePrimitive`. This is what the registry stores
+/// and the runtime calls; every `ComputePrimitive` gets it through the blanket impl.
+pub trait FallibleComputePrimitive: Send + Sync {
+    fn manifest(&self) -> &ComputePrimitiveManifest;
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError>;
+}
+
+impl<T: ComputePrimitive> FallibleComputePrimitive for T {
+    fn manifest(&self) -> &ComputePrimitiveManifest {
+        ComputePrimitive::manifest(self)
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, Value>,
+        parameters: &HashMap<String, Value>,
+        state: Option<&mut PrimitiveState>,
+    ) -> Result<HashMap<String, Value>, ComputeError> {
+        Ok(self.compute(inputs, parameters, state))
+    }
+}
+
+pub use crate::common::parameter::{ParameterSpec, ParameterType, ParameterValue};
 pub use graph::{ComputeGraph, GraphNode, InputBinding, NodeOutputRef};
 pub use implementations::{
-    add, and, const_bool, const_number, divide, eq, gt, lt, multiply, negate, neq, not, or, select,
-    subtract, Add, And, ConstBool, ConstNumber, Divide, Eq, Gt, Lt, Multiply, Negate, Neq, Not, Or,
-    Select, Subtract,
+    abs, add, and, approx_eq, at, between, ceil, clamp, concat, const_bool, const_number, cumsum,
+    diff, divide, eq, exp, floor, format, gt, gte, implies, lag, last, len, ln, lt, lte, max, min,
+    modulo, multiply, nand, negate, neq, not, or, pow, rolling_max, rolling_mean, rolling_min,
+    rolling_stdev, rolling_sum, round, safe_divide, select, sign, slice, sqrt, str_contains,
+    str_eq, subtract, to_string, xor, Abs, Add, And, ApproxEq, At, Between, Ceil, Clamp, Concat,
+    ConstBool, ConstNumber, Cumsum, Diff, Divide, Eq, Exp, Floor, Format, Gt, Gte, Implies, Lag,
+    Last, Len, Ln, Lt, Lte, Max, Min, Modulo, Multiply, Nand, Negate, Neq, Not, NumberToString, Or,
+    Pow, RollingMax, RollingMean, RollingMin, RollingStdev, RollingSum, Round, SafeDivide, Select,
+    Sign, Slice, Sqrt, StrContains, StrEq, Subtract, Xor,
 };
 pub use registry::PrimitiveRegistry;
 
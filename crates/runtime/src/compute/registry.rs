@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
-use crate::common::{PrimitiveKind, ValidationError};
-use crate::compute::{ComputePrimitive, ComputePrimitiveManifest};
+use crate::common::{PrimitiveKind, ValidationError, ValueType};
+use crate::compute::{ComputePrimitiveManifest, FallibleComputePrimitive, ParameterSpec};
 
 pub struct PrimitiveRegistry {
-    primitives: HashMap<String, Box<dyn ComputePrimitive>>,
+    /// Keyed by (id, version) so several versions of a primitive can coexist.
+    primitives: HashMap<(String, String), Box<dyn FallibleComputePrimitive>>,
 }
 
 impl PrimitiveRegistry {
@@ -37,27 +38,66 @@ impl PrimitiveRegistry {
             });
         }
 
+        Self::validate_parameters(&manifest.parameters)?;
+        Self::validate_type_variables(manifest)?;
+
+        Ok(())
+    }
+
+    fn validate_type_variables(manifest: &ComputePrimitiveManifest) -> Result<(), ValidationError> {
+        for output in &manifest.outputs {
+            let ValueType::Var(variable) = &output.value_type else {
+                continue;
+            };
+            if !manifest
+                .inputs
+                .iter()
+                .any(|i| i.value_type == output.value_type)
+            {
+                return Err(ValidationError::UnboundTypeVariable {
+                    output: output.name.clone(),
+                    variable: *variable,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Each declaration must parse and pass `common::parameter::check_declaration`.
+    fn validate_parameters(parameters: &[ParameterSpec]) -> Result<(), ValidationError> {
+        for param in parameters {
+            param.parsed_bounds().map_err(|error| {
+                ValidationError::InvalidParameterDeclaration {
+                    parameter: param.name.clone(),
+                    error,
+                }
+            })?;
+        }
         Ok(())
     }
 
     pub fn register(
         &mut self,
-        primitive: Box<dyn ComputePrimitive>,
+        primitive: Box<dyn FallibleComputePrimitive>,
     ) -> Result<(), ValidationError> {
         let manifest = primitive.manifest();
 
         Self::validate_manifest(manifest)?;
 
-        if self.primitives.contains_key(&manifest.id) {
+        let key = (manifest.id.clone(), manifest.version.clone());
+        if self.primitives.contains_key(&key) {
             return Err(ValidationError::DuplicateId(manifest.id.clone()));
         }
 
-        self.primitives.insert(manifest.id.clone(), primitive);
+        self.primitives.insert(key, primitive);
         Ok(())
     }
 
-    pub fn get(&self, id: &str) -> Option<&Box<dyn ComputePrimitive>> {
-        self.primitives.get(id)
+    /// Exact lookup; there is no fallback to another version of the same id.
+    pub fn get(&self, id: &str, version: &str) -> Option<&dyn FallibleComputePrimitive> {
+        self.primitives
+            .get(&(id.to_string(), version.to_string()))
+            .map(|p| p.as_ref())
     }
 }
 
@@ -70,10 +110,11 @@ impl Default for PrimitiveRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::common::{PrimitiveKind, Value, ValueType};
+    use crate::common::parameter::ParameterDeclarationError;
+    use crate::common::{ParameterType, ParameterValue, PrimitiveKind, Value, ValueType};
     use crate::compute::{
-        Cadence, ComputePrimitive, ComputePrimitiveManifest, ExecutionSpec, InputSpec, OutputSpec,
-        PrimitiveState, StateSpec,
+        Cadence, Cardinality, ComputePrimitive, ComputePrimitiveManifest, ExecutionSpec, InputSpec,
+        OutputSpec, PrimitiveState, StateSpec,
     };
 
     struct ZeroInputCompute {
@@ -137,6 +178,7 @@ mod tests {
                         name: "in".to_string(),
                         value_type: ValueType::Number,
                         required: true,
+                        cardinality: Cardinality::Single,
                     }],
                     outputs: vec![OutputSpec {
                         name: "out".to_string(),
@@ -192,4 +234,88 @@ mod tests {
         let result = registry.register(Box::new(SingleInputCompute::new()));
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn type_variables_must_be_bound_by_an_input() {
+        let mut registry = PrimitiveRegistry::new();
+        let mut unbound = SingleInputCompute::new();
+        unbound.manifest.outputs[0].value_type = ValueType::Var('T');
+        assert_eq!(
+            registry.register(Box::new(unbound)).unwrap_err(),
+            ValidationError::UnboundTypeVariable {
+                output: "out".to_string(),
+                variable: 'T',
+            }
+        );
+
+        let mut generic = SingleInputCompute::new();
+        generic.manifest.inputs[0].value_type = ValueType::Var('T');
+        generic.manifest.outputs[0].value_type = ValueType::Var('T');
+        assert!(registry.register(Box::new(generic)).is_ok());
+    }
+
+    #[test]
+    fn enum_parameters_must_declare_their_variants() {
+        let mut registry = PrimitiveRegistry::new();
+        let mut mode = SingleInputCompute::new();
+        mode.manifest.parameters.push(ParameterSpec {
+            name: "mode".to_string(),
+            value_type: ParameterType::Enum,
+            default: Some(ParameterValue::Enum("fast".to_string())),
+            bounds: None,
+            variants: Vec::new(),
+        });
+        assert_eq!(
+            registry.register(Box::new(mode)).unwrap_err(),
+            ValidationError::InvalidParameterDeclaration {
+                parameter: "mode".to_string(),
+                error: ParameterDeclarationError::EnumVariantsRequired,
+            }
+        );
+
+        let mut mode = SingleInputCompute::new();
+        mode.manifest.parameters.push(ParameterSpec {
+            name: "mode".to_string(),
+            value_type: ParameterType::Enum,
+            default: Some(ParameterValue::Enum("fast".to_string())),
+            bounds: None,
+            variants: vec!["fast".to_string(), "exact".to_string()],
+        });
+        assert!(registry.register(Box::new(mode)).is_ok());
+    }
+
+    #[test]
+    fn versions_coexist_and_lookup_is_exact() {
+        let mut registry = PrimitiveRegistry::new();
+        let mut v2 = SingleInputCompute::new();
+        v2.manifest.version = "0.2.0".to_string();
+
+        registry
+            .register(Box::new(SingleInputCompute::new()))
+            .unwrap();
+        registry.register(Box::new(v2)).unwrap();
+
+        assert_eq!(
+            registry
+                .get("single_input", "0.1.0")
+                .unwrap()
+                .manifest()
+                .version,
+            "0.1.0"
+        );
+        assert_eq!(
+            registry
+                .get("single_input", "0.2.0")
+                .unwrap()
+                .manifest()
+                .version,
+            "0.2.0"
+        );
+        assert!(registry.get("single_input", "0.3.0").is_none());
+
+        let err = registry
+            .register(Box::new(SingleInputCompute::new()))
+            .unwrap_err();
+        assert!(matches!(err, ValidationError::DuplicateId(id) if id == "single_input"));
+    }
 }
@@ -2,10 +2,13 @@ use std::collections::HashMap;
 
 use crate::common::Value;
 use crate::compute::implementations::{
-    Add, And, ConstBool, ConstNumber, Divide, Eq, Gt, Lt, Multiply, Negate, Neq, Not, Or, Select,
-    Subtract,
+    Abs, Add, And, ApproxEq, At, Between, Ceil, Clamp, Concat, ConstBool, ConstNumber, Cumsum,
+    Diff, Divide, Eq, Exp, Floor, Format, Gt, Gte, Implies, Lag, Last, Len, Ln, Lt, Lte, Max, Min,
+    Modulo, Multiply, Nand, Negate, Neq, Not, NumberToString, Or, Pow, RollingMax, RollingMean,
+    RollingMin, RollingStdev, RollingSum, Round, SafeDivide, Select, Sign, Slice, Sqrt,
+    StrContains, StrEq, Subtract, Xor,
 };
-use crate::compute::ComputePrimitive;
+use crate::compute::{ComputeError, ComputePrimitive, FallibleComputePrimitive};
 
 fn expect_panic<F: FnOnce() -> R + std::panic::UnwindSafe, R>(f: F) {
     assert!(std::panic::catch_unwind(f).is_err());
@@ -129,6 +132,41 @@ fn divide_requires_inputs_and_computes() {
     });
 }
 
+#[test]
+fn safe_divide_falls_back_when_the_quotient_is_not_finite() {
+    let safe_divide = SafeDivide::new();
+    let inputs = |a: f64, b: f64| {
+        HashMap::from([
+            ("a".to_string(), Value::Number(a)),
+            ("b".to_string(), Value::Number(b)),
+        ])
+    };
+    let fallback = HashMap::from([("fallback".to_string(), Value::Number(-1.0))]);
+    let divide = |a: f64, b: f64| {
+        safe_divide
+            .try_compute(&inputs(a, b), &fallback, None)
+            .map(|outputs| outputs["result"].clone())
+    };
+    assert_eq!(divide(8.0, 2.0), Ok(Value::Number(4.0)));
+    assert_eq!(divide(1.0, 0.0), Ok(Value::Number(-1.0)));
+    assert_eq!(divide(0.0, 0.0), Ok(Value::Number(-1.0)));
+
+    assert!(matches!(
+        safe_divide.try_compute(&inputs(1.0, 2.0), &HashMap::new(), None),
+        Err(ComputeError::InvalidParameter { name, .. }) if name == "fallback"
+    ));
+    assert_eq!(
+        safe_divide.try_compute(
+            &HashMap::from([("a".to_string(), Value::Number(1.0))]),
+            &fallback,
+            None,
+        ),
+        Err(ComputeError::MissingInput {
+            name: "b".to_string()
+        })
+    );
+}
+
 #[test]
 fn negate_requires_input_and_computes() {
     let negate = Negate::new();
@@ -312,3 +350,564 @@ fn select_requires_all_inputs_and_routes_without_casts() {
         );
     });
 }
+
+#[test]
+fn select_routes_any_value_type() {
+    let select = Select::new();
+    let outputs = select.compute(
+        &HashMap::from([
+            ("cond".to_string(), Value::Bool(false)),
+            ("when_true".to_string(), Value::Series(vec![1.0])),
+            ("when_false".to_string(), Value::Series(vec![2.0, 3.0])),
+        ]),
+        &HashMap::new(),
+        None,
+    );
+    assert_eq!(outputs.get("result"), Some(&Value::Series(vec![2.0, 3.0])));
+
+    let outputs = select.compute(
+        &HashMap::from([
+            ("cond".to_string(), Value::Bool(true)),
+            ("when_true".to_string(), Value::Bool(true)),
+            ("when_false".to_string(), Value::Bool(false)),
+        ]),
+        &HashMap::new(),
+        None,
+    );
+    assert_eq!(outputs.get("result"), Some(&Value::Bool(true)));
+}
+
+fn unary(
+    primitive: &dyn FallibleComputePrimitive,
+    value: f64,
+    parameters: &[(&str, f64)],
+) -> Result<f64, ComputeError> {
+    let parameters = parameters
+        .iter()
+        .map(|(name, n)| (name.to_string(), Value::Number(*n)))
+        .collect();
+    primitive
+        .try_compute(
+            &HashMap::from([("value".to_string(), Value::Number(value))]),
+            &parameters,
+            None,
+        )
+        .map(|outputs| outputs["result"].as_number().expect("numeric result"))
+}
+
+fn binary(primitive: &dyn FallibleComputePrimitive, a: f64, b: f64) -> Result<f64, ComputeError> {
+    primitive
+        .try_compute(
+            &HashMap::from([
+                ("a".to_string(), Value::Number(a)),
+                ("b".to_string(), Value::Number(b)),
+            ]),
+            &HashMap::new(),
+            None,
+        )
+        .map(|outputs| outputs["result"].as_number().expect("numeric result"))
+}
+
+#[test]
+fn unary_math_requires_input_and_computes() {
+    assert_eq!(unary(&Abs::new(), -2.5, &[]), Ok(2.5));
+    assert_eq!(unary(&Sqrt::new(), 9.0, &[]), Ok(3.0));
+    assert_eq!(unary(&Ln::new(), 1.0, &[]), Ok(0.0));
+    assert_eq!(unary(&Exp::new(), 0.0, &[]), Ok(1.0));
+    assert_eq!(unary(&Sign::new(), -4.0, &[]), Ok(-1.0));
+    assert_eq!(unary(&Sign::new(), 0.0, &[]), Ok(0.0));
+    assert_eq!(unary(&Sign::new(), 7.0, &[]), Ok(1.0));
+
+    for primitive in [
+        &Abs::new() as &dyn FallibleComputePrimitive,
+        &Sqrt::new(),
+        &Ln::new(),
+        &Exp::new(),
+        &Sign::new(),
+    ] {
+        assert_eq!(
+            primitive.try_compute(&HashMap::new(), &HashMap::new(), None),
+            Err(ComputeError::MissingInput {
+                name: "value".to_string()
+            })
+        );
+    }
+    assert!(matches!(
+        Abs::new().try_compute(
+            &HashMap::from([("value".to_string(), Value::Bool(true))]),
+            &HashMap::new(),
+            None,
+        ),
+        Err(ComputeError::InvalidInput { name, .. }) if name == "value"
+    ));
+}
+
+#[test]
+fn rounding_respects_precision() {
+    assert_eq!(unary(&Round::new(), 2.5, &[("precision", 0.0)]), Ok(3.0));
+    assert_eq!(
+        unary(&Round::new(), 1.2345, &[("precision", 2.0)]),
+        Ok(1.23)
+    );
+    assert_eq!(
+        unary(&Round::new(), 1250.0, &[("precision", -2.0)]),
+        Ok(1300.0)
+    );
+    assert_eq!(unary(&Floor::new(), -1.25, &[("precision", 1.0)]), Ok(-1.3));
+    assert_eq!(unary(&Ceil::new(), 1.21, &[("precision", 1.0)]), Ok(1.3));
+
+    for precision in [&[][..], &[("precision", 1.5)], &[("precision", 16.0)]] {
+        assert!(matches!(
+            unary(&Round::new(), 1.0, precision),
+            Err(ComputeError::InvalidParameter { name, .. }) if name == "precision"
+        ));
+    }
+}
+
+#[test]
+fn binary_math_requires_inputs_and_computes() {
+    assert_eq!(binary(&Min::new(), 2.0, -1.0), Ok(-1.0));
+    assert_eq!(binary(&Max::new(), 2.0, -1.0), Ok(2.0));
+    assert_eq!(binary(&Pow::new(), 2.0, 10.0), Ok(1024.0));
+    assert_eq!(binary(&Modulo::new(), 7.0, 3.0), Ok(1.0));
+    assert_eq!(binary(&Modulo::new(), -7.0, 3.0), Ok(2.0));
+
+    assert_eq!(
+        Pow::new().try_compute(
+            &HashMap::from([("a".to_string(), Value::Number(2.0))]),
+            &HashMap::new(),
+            None,
+        ),
+        Err(ComputeError::MissingInput {
+            name: "b".to_string()
+        })
+    );
+}
+
+#[test]
+fn clamp_bounds_value_and_rejects_an_inverted_range() {
+    let clamp = |value: f64, min: f64, max: f64| {
+        Clamp::new()
+            .try_compute(
+                &HashMap::from([
+                    ("value".to_string(), Value::Number(value)),
+                    ("min".to_string(), Value::Number(min)),
+                    ("max".to_string(), Value::Number(max)),
+                ]),
+                &HashMap::new(),
+                None,
+            )
+            .map(|outputs| outputs["result"].clone())
+    };
+    assert_eq!(clamp(5.0, 0.0, 1.0), Ok(Value::Number(1.0)));
+    assert_eq!(clamp(-5.0, 0.0, 1.0), Ok(Value::Number(0.0)));
+    assert_eq!(clamp(0.5, 0.0, 1.0), Ok(Value::Number(0.5)));
+    assert!(matches!(
+        clamp(0.5, 1.0, 0.0),
+        Err(ComputeError::InvalidInput { .. })
+    ));
+    assert_eq!(
+        Clamp::new().try_compute(&HashMap::new(), &HashMap::new(), None),
+        Err(ComputeError::MissingInput {
+            name: "value".to_string()
+        })
+    );
+}
+
+fn truth(
+    primitive: &dyn FallibleComputePrimitive,
+    inputs: &[(&str, Value)],
+    parameters: &[(&str, Value)],
+) -> Result<bool, ComputeError> {
+    let collect = |pairs: &[(&str, Value)]| {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect::<HashMap<_, _>>()
+    };
+    primitive
+        .try_compute(&collect(inputs), &collect(parameters), None)
+        .map(|outputs| outputs["result"].as_bool().expect("bool result"))
+}
+
+#[test]
+fn inclusive_comparisons_include_equality() {
+    let pair = |a: f64, b: f64| [("a", Value::Number(a)), ("b", Value::Number(b))];
+    assert_eq!(truth(&Gte::new(), &pair(2.0, 2.0), &[]), Ok(true));
+    assert_eq!(truth(&Gte::new(), &pair(1.0, 2.0), &[]), Ok(false));
+    assert_eq!(truth(&Lte::new(), &pair(2.0, 2.0), &[]), Ok(true));
+    assert_eq!(truth(&Lte::new(), &pair(3.0, 2.0), &[]), Ok(false));
+
+    let epsilon = [("epsilon", Value::Number(0.01))];
+    assert_eq!(
+        truth(&ApproxEq::new(), &pair(0.1 + 0.2, 0.3), &epsilon),
+        Ok(true)
+    );
+    assert_eq!(
+        truth(&ApproxEq::new(), &pair(1.0, 1.1), &epsilon),
+        Ok(false)
+    );
+
+    assert_eq!(
+        truth(&Gte::new(), &[("a", Value::Number(1.0))], &[]),
+        Err(ComputeError::MissingInput {
+            name: "b".to_string()
+        })
+    );
+    assert!(matches!(
+        truth(&Lte::new(), &[("a", Value::Bool(true)), ("b", Value::Number(1.0))], &[]),
+        Err(ComputeError::InvalidInput { name, .. }) if name == "a"
+    ));
+    assert!(matches!(
+        truth(&ApproxEq::new(), &pair(1.0, 1.0), &[]),
+        Err(ComputeError::InvalidParameter { name, .. }) if name == "epsilon"
+    ));
+}
+
+#[test]
+fn between_honours_bounds_parameter() {
+    let range = |value: f64| {
+        [
+            ("value", Value::Number(value)),
+            ("low", Value::Number(0.0)),
+            ("high", Value::Number(1.0)),
+        ]
+    };
+    let inclusive = [("bounds", Value::String("inclusive".to_string()))];
+    let exclusive = [("bounds", Value::String("exclusive".to_string()))];
+
+    assert_eq!(truth(&Between::new(), &range(0.0), &inclusive), Ok(true));
+    assert_eq!(truth(&Between::new(), &range(1.0), &inclusive), Ok(true));
+    assert_eq!(truth(&Between::new(), &range(0.0), &exclusive), Ok(false));
+    assert_eq!(truth(&Between::new(), &range(0.5), &exclusive), Ok(true));
+    assert_eq!(truth(&Between::new(), &range(1.5), &inclusive), Ok(false));
+
+    let inverted = [
+        ("value", Value::Number(0.5)),
+        ("low", Value::Number(1.0)),
+        ("high", Value::Number(0.0)),
+    ];
+    assert_eq!(truth(&Between::new(), &inverted, &inclusive), Ok(false));
+
+    assert!(matches!(
+        truth(&Between::new(), &range(0.5), &[]),
+        Err(ComputeError::InvalidParameter { name, .. }) if name == "bounds"
+    ));
+    assert_eq!(
+        truth(&Between::new(), &range(0.5)[..2], &inclusive),
+        Err(ComputeError::MissingInput {
+            name: "high".to_string()
+        })
+    );
+}
+
+#[test]
+fn extended_boolean_ops_follow_their_truth_tables() {
+    let cases = [(false, false), (false, true), (true, false), (true, true)];
+    let table = |primitive: &dyn FallibleComputePrimitive| {
+        cases.map(|(a, b)| {
+            truth(
+                primitive,
+                &[("a", Value::Bool(a)), ("b", Value::Bool(b))],
+                &[],
+            )
+            .expect("bool inputs")
+        })
+    };
+    assert_eq!(table(&Xor::new()), [false, true, true, false]);
+    assert_eq!(table(&Nand::new()), [true, true, true, false]);
+    assert_eq!(table(&Implies::new()), [true, true, false, true]);
+
+    assert_eq!(
+        truth(&Implies::new(), &[("a", Value::Bool(true))], &[]),
+        Err(ComputeError::MissingInput {
+            name: "b".to_string()
+        })
+    );
+    assert!(matches!(
+        truth(&Xor::new(), &[("a", Value::Number(1.0)), ("b", Value::Bool(true))], &[]),
+        Err(ComputeError::InvalidInput { name, .. }) if name == "a"
+    ));
+}
+
+fn series(values: &[f64]) -> HashMap<String, Value> {
+    HashMap::from([("series".to_string(), Value::Series(values.to_vec()))])
+}
+
+fn numbers(parameters: &[(&str, f64)]) -> HashMap<String, Value> {
+    parameters
+        .iter()
+        .map(|(name, n)| (name.to_string(), Value::Number(*n)))
+        .collect()
+}
+
+fn series_result(
+    primitive: &dyn FallibleComputePrimitive,
+    values: &[f64],
+    parameters: &[(&str, f64)],
+) -> Result<Value, ComputeError> {
+    primitive
+        .try_compute(&series(values), &numbers(parameters), None)
+        .map(|mut outputs| outputs.remove("result").expect("result output"))
+}
+
+#[test]
+fn series_accessors_read_length_and_positions() {
+    let values = [1.0, 2.0, 3.0];
+    assert_eq!(
+        series_result(&Len::new(), &values, &[]),
+        Ok(Value::Number(3.0))
+    );
+    assert_eq!(series_result(&Len::new(), &[], &[]), Ok(Value::Number(0.0)));
+    assert_eq!(
+        series_result(&Last::new(), &values, &[]),
+        Ok(Value::Number(3.0))
+    );
+    assert_eq!(
+        series_result(&At::new(), &values, &[("index", 0.0)]),
+        Ok(Value::Number(1.0))
+    );
+    assert_eq!(
+        series_result(&At::new(), &values, &[("index", -2.0)]),
+        Ok(Value::Number(2.0))
+    );
+
+    // No value to extract: the pass fails instead of inventing one.
+    assert!(matches!(
+        series_result(&Last::new(), &[], &[]),
+        Err(ComputeError::InvalidInput { .. })
+    ));
+    for index in [3.0, -4.0] {
+        assert!(matches!(
+            series_result(&At::new(), &values, &[("index", index)]),
+            Err(ComputeError::InvalidInput { .. })
+        ));
+    }
+    assert!(matches!(
+        series_result(&At::new(), &values, &[("index", 0.5)]),
+        Err(ComputeError::InvalidParameter { .. })
+    ));
+    assert_eq!(
+        Len::new().try_compute(&HashMap::new(), &HashMap::new(), None),
+        Err(ComputeError::MissingInput {
+            name: "series".to_string()
+        })
+    );
+}
+
+#[test]
+fn slice_and_lag_clamp_to_the_series() {
+    let values = [1.0, 2.0, 3.0, 4.0];
+    assert_eq!(
+        series_result(&Slice::new(), &values, &[("start", 1.0), ("count", 2.0)]),
+        Ok(Value::Series(vec![2.0, 3.0]))
+    );
+    assert_eq!(
+        series_result(&Slice::new(), &values, &[("start", -2.0), ("count", 5.0)]),
+        Ok(Value::Series(vec![3.0, 4.0]))
+    );
+    assert_eq!(
+        series_result(&Slice::new(), &values, &[("start", 9.0), ("count", 1.0)]),
+        Ok(Value::Series(vec![]))
+    );
+
+    assert_eq!(
+        series_result(&Lag::new(), &values, &[("periods", 1.0)]),
+        Ok(Value::Series(vec![1.0, 2.0, 3.0]))
+    );
+    assert_eq!(
+        series_result(&Lag::new(), &values, &[("periods", 6.0)]),
+        Ok(Value::Series(vec![]))
+    );
+}
+
+#[test]
+fn rolling_aggregates_emit_one_value_per_complete_window() {
+    let values = [1.0, 3.0, 2.0, 6.0];
+    let window = [("window", 2.0)];
+    assert_eq!(
+        series_result(&RollingSum::new(), &values, &window),
+        Ok(Value::Series(vec![4.0, 5.0, 8.0]))
+    );
+    assert_eq!(
+        series_result(&RollingMean::new(), &values, &window),
+        Ok(Value::Series(vec![2.0, 2.5, 4.0]))
+    );
+    assert_eq!(
+        series_result(&RollingMin::new(), &values, &window),
+        Ok(Value::Series(vec![1.0, 2.0, 2.0]))
+    );
+    assert_eq!(
+        series_result(&RollingMax::new(), &values, &window),
+        Ok(Value::Series(vec![3.0, 3.0, 6.0]))
+    );
+    assert_eq!(
+        series_result(&RollingStdev::new(), &[1.0, 3.0, 5.0], &window),
+        Ok(Value::Series(vec![2f64.sqrt(), 2f64.sqrt()]))
+    );
+
+    // A series shorter than the window has no complete window.
+    assert_eq!(
+        series_result(&RollingMean::new(), &[1.0], &window),
+        Ok(Value::Series(vec![]))
+    );
+    assert!(matches!(
+        series_result(&RollingStdev::new(), &values, &[("window", 1.0)]),
+        Err(ComputeError::InvalidParameter { .. })
+    ));
+}
+
+#[test]
+fn cumsum_and_diff_accumulate_and_difference() {
+    let values = [1.0, 2.0, 4.0, 7.0];
+    assert_eq!(
+        series_result(&Cumsum::new(), &values, &[]),
+        Ok(Value::Series(vec![1.0, 3.0, 7.0, 14.0]))
+    );
+    assert_eq!(
+        series_result(&Diff::new(), &values, &[("periods", 1.0)]),
+        Ok(Value::Series(vec![1.0, 2.0, 3.0]))
+    );
+    assert_eq!(
+        series_result(&Diff::new(), &values, &[("periods", 2.0)]),
+        Ok(Value::Series(vec![3.0, 5.0]))
+    );
+    assert_eq!(
+        series_result(&Cumsum::new(), &[], &[]),
+        Ok(Value::Series(vec![]))
+    );
+    assert_eq!(
+        series_result(&Diff::new(), &[1.0], &[("periods", 1.0)]),
+        Ok(Value::Series(vec![]))
+    );
+}
+
+fn strings(values: &[(&str, &str)]) -> HashMap<String, Value> {
+    values
+        .iter()
+        .map(|(name, value)| (name.to_string(), Value::String(value.to_string())))
+        .collect()
+}
+
+#[test]
+fn string_computes_concatenate_and_compare() {
+    let run = |primitive: &dyn ComputePrimitive, inputs: &[(&str, &str)]| {
+        primitive.compute(&strings(inputs), &HashMap::new(), None)["result"].clone()
+    };
+
+    assert_eq!(
+        run(&Concat::new(), &[("a", "buy "), ("b", "EURUSD")]),
+        Value::String("buy EURUSD".to_string())
+    );
+    assert_eq!(
+        run(&StrEq::new(), &[("a", "buy"), ("b", "buy")]),
+        Value::Bool(true)
+    );
+    assert_eq!(
+        run(&StrEq::new(), &[("a", "buy"), ("b", "Buy")]),
+        Value::Bool(false)
+    );
+    assert_eq!(
+        run(
+            &StrContains::new(),
+            &[("value", "EURUSD"), ("substring", "USD")]
+        ),
+        Value::Bool(true)
+    );
+    assert_eq!(
+        run(
+            &StrContains::new(),
+            &[("value", "EURUSD"), ("substring", "JPY")]
+        ),
+        Value::Bool(false)
+    );
+
+    expect_panic(|| {
+        Concat::new().compute(&strings(&[("a", "x")]), &HashMap::new(), None);
+    });
+    expect_panic(|| {
+        StrEq::new().compute(
+            &HashMap::from([("a".to_string(), Value::Number(1.0))]),
+            &HashMap::new(),
+            None,
+        );
+    });
+}
+
+#[test]
+fn to_string_formats_with_fixed_precision() {
+    let to_string = NumberToString::new();
+    let run = |value: f64, precision: f64| {
+        to_string
+            .try_compute(
+                &HashMap::from([("value".to_string(), Value::Number(value))]),
+                &numbers(&[("precision", precision)]),
+                None,
+            )
+            .map(|outputs| outputs["result"].clone())
+    };
+    assert_eq!(run(1.23456, 2.0), Ok(Value::String("1.23".to_string())));
+    assert_eq!(run(2.5, 0.0), Ok(Value::String("2".to_string())));
+    assert_eq!(run(-3.0, 3.0), Ok(Value::String("-3.000".to_string())));
+
+    for precision in [1.5, -1.0] {
+        assert!(matches!(
+            run(1.0, precision),
+            Err(ComputeError::InvalidParameter { name, .. }) if name == "precision"
+        ));
+    }
+    assert_eq!(
+        to_string.try_compute(&HashMap::new(), &numbers(&[("precision", 1.0)]), None),
+        Err(ComputeError::MissingInput {
+            name: "value".to_string()
+        })
+    );
+}
+
+#[test]
+fn format_fills_placeholders_from_wired_inputs() {
+    let format = Format::new();
+    let run = |template: &str, inputs: &[(&str, &str)]| {
+        format.try_compute(
+            &strings(inputs),
+            &HashMap::from([("template".to_string(), Value::String(template.to_string()))]),
+            None,
+        )
+    };
+
+    assert_eq!(
+        run(
+            "{a} {b} @ {c}",
+            &[("a", "buy"), ("b", "EURUSD"), ("c", "1.08")]
+        )
+        .unwrap()["result"],
+        Value::String("buy EURUSD @ 1.08".to_string())
+    );
+    assert_eq!(
+        run("{{{a}}}", &[("a", "x")]).unwrap()["result"],
+        Value::String("{x}".to_string())
+    );
+    assert_eq!(
+        run("no placeholders", &[]).unwrap()["result"],
+        Value::String("no placeholders".to_string())
+    );
+
+    assert!(matches!(
+        run("{b}", &[("a", "x")]),
+        Err(ComputeError::MissingInput { name }) if name == "b"
+    ));
+    for template in ["{e}", "{a", "a}", "{}"] {
+        assert!(
+            matches!(
+                run(template, &[("a", "x")]),
+                Err(ComputeError::InvalidParameter { name, .. }) if name == "template"
+            ),
+            "{}",
+            template
+        );
+    }
+    assert!(matches!(
+        format.try_compute(&strings(&[("a", "x")]), &HashMap::new(), None),
+        Err(ComputeError::InvalidParameter { name, .. }) if name == "template"
+    ));
+}
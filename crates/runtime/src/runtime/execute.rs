@@ -1,12 +1,17 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::panic::{self, AssertUnwindSafe};
 
-use crate::action::{ActionOutcome, ActionValue};
+use crate::action::{ActionContext, ActionOutcome, ActionValue};
 use crate::cluster::{PrimitiveKind, ValueType};
+use crate::common::Value;
+use crate::compute::{PrimitiveRegistry as ComputeRegistry, PrimitiveState};
+use crate::source::SourceContext;
 use crate::trigger::{TriggerEvent, TriggerState, TriggerValue};
 
+use super::plan::{ExecutionPlan, PlannedNode, Slots};
 use super::types::{
-    Endpoint, ExecError, ExecutionContext, ExecutionReport, Registries, RuntimeEvent, RuntimeValue,
-    ValidatedEdge, ValidatedGraph, ValidatedNode,
+    ExecError, ExecutionContext, ExecutionReport, NodeTrace, NonFinitePolicy, Registries,
+    RuntimeEvent, RuntimeValue, ValidatedGraph, ValidatedNode,
 };
 
 pub fn execute(
@@ -14,144 +19,299 @@ pub fn execute(
     registries: &Registries,
     ctx: &ExecutionContext,
 ) -> Result<ExecutionReport, ExecError> {
-    let mut node_outputs: HashMap<String, HashMap<String, RuntimeValue>> = HashMap::new();
-    let mut trigger_state = ctx.trigger_state.clone();
+    execute_plan(&ExecutionPlan::compile(graph), registries, ctx)
+}
 
-    for node_id in &graph.topo_order {
-        let node = graph.nodes.get(node_id).expect("validated node missing");
+/// Executes a precompiled plan; compile once with `ExecutionPlan::compile` and reuse
+/// it across passes to avoid recompiling the graph on every call.
+pub fn execute_plan(
+    plan: &ExecutionPlan,
+    registries: &Registries,
+    ctx: &ExecutionContext,
+) -> Result<ExecutionReport, ExecError> {
+    execute_pass(plan, registries, ctx, None).map(|(report, _)| report)
+}
 
-        let inputs = collect_inputs(node_id, &node.inputs, &graph.edges, &node_outputs)?;
+/// One evaluation pass. With `previous` set, every slot survives the pass so it can be
+/// compared next time, and a stateless Compute or Trigger node whose upstream outputs
+/// all equal those in `previous` reuses its previous outputs instead of being
+/// evaluated. An empty `previous` reuses nothing. Sources, stateful Computes and
+/// Actions always run.
+pub(super) fn execute_pass(
+    plan: &ExecutionPlan,
+    registries: &Registries,
+    ctx: &ExecutionContext,
+    previous: Option<&Slots>,
+) -> Result<(ExecutionReport, Slots), ExecError> {
+    let mut slots: Slots = vec![None; plan.slot_count()];
+    // Reads left per slot; the last read moves the value out instead of cloning it.
+    // Incremental passes keep every slot, so they never move.
+    let mut remaining = previous.is_none().then(|| plan.consumers.clone());
+    let mut trigger_state = ctx.trigger_state.clone();
+    let mut compute_state = declared_compute_state(plan, registries, ctx)?;
+    let mut trace: Option<Vec<NodeTrace>> = ctx.trace.then(Vec::new);
+    let mut environment_writes = BTreeMap::new();
+    // Whether each node's outputs differ from `previous` (or it has none there).
+    let mut changed = vec![true; plan.node_count()];
+
+    for (index, planned) in plan.nodes.iter().enumerate() {
+        let node = &planned.node;
+        let prior = previous
+            .filter(|p| !p.is_empty())
+            .map(|p| &p[planned.slots.clone()]);
+
+        let reusable = match node.kind {
+            PrimitiveKind::Compute => !compute_state.contains_key(&node.runtime_id),
+            PrimitiveKind::Trigger => true,
+            PrimitiveKind::Source | PrimitiveKind::Action => false,
+        };
+        let reuse = prior.filter(|_| reusable && !planned.upstream.iter().any(|&u| changed[u]));
 
-        let outputs = match node.kind {
-            PrimitiveKind::Source => execute_source(node, inputs, registries)?,
-            PrimitiveKind::Compute => execute_compute(node, inputs, registries)?,
-            PrimitiveKind::Trigger => {
-                execute_trigger(node, inputs, registries, &mut trigger_state)?
-            }
-            PrimitiveKind::Action => {
-                // R.7: Actions execute only when all trigger event inputs are Emitted.
-                // If any Event input is TriggerEvent::NotEmitted, skip execution.
-                if should_skip_action(&inputs) {
-                    produce_skipped_outputs(node)
-                } else {
-                    execute_action(node, inputs, registries)?
-                }
-            }
+        let inputs = if reuse.is_none() || trace.is_some() {
+            gather_inputs(plan, planned, &mut slots, remaining.as_deref_mut())?
+        } else {
+            HashMap::new()
         };
+        let traced_inputs = trace.as_ref().map(|_| inputs.clone());
+        let mut skipped = false;
+
+        match reuse {
+            Some(prior) => slots[planned.slots.clone()].clone_from_slice(prior),
+            None => {
+                let outputs = match node.kind {
+                    PrimitiveKind::Source => execute_source(node, inputs, registries, ctx)?,
+                    PrimitiveKind::Compute => execute_compute(
+                        node,
+                        inputs,
+                        registries.computes,
+                        compute_state.get_mut(&node.runtime_id),
+                        ctx.non_finite,
+                    )?,
+                    PrimitiveKind::Trigger => {
+                        execute_trigger(node, inputs, registries, &mut trigger_state)?
+                    }
+                    PrimitiveKind::Action => {
+                        // R.7: Actions execute only when all trigger event inputs are Emitted.
+                        // If any Event input is TriggerEvent::NotEmitted, skip execution.
+                        if should_skip_action(&inputs) {
+                            skipped = true;
+                            produce_skipped_outputs(node)
+                        } else {
+                            execute_action(node, inputs, registries, &mut environment_writes)?
+                        }
+                    }
+                };
+                store_outputs(planned, outputs, &mut slots);
+            }
+        }
 
-        node_outputs.insert(node_id.clone(), outputs);
+        changed[index] = prior != Some(&slots[planned.slots.clone()]);
+
+        if let (Some(trace), Some(inputs)) = (trace.as_mut(), traced_inputs) {
+            trace.push(NodeTrace {
+                node: node.runtime_id.clone(),
+                impl_id: node.impl_id.clone(),
+                version: node.version.clone(),
+                kind: node.kind.clone(),
+                inputs,
+                parameters: node.parameters.clone(),
+                outputs: produced_outputs(planned, &slots),
+                skipped,
+            });
+        }
     }
 
+    let report = ExecutionReport {
+        outputs: boundary_outputs(plan, &slots)?,
+        trace,
+        compute_state,
+        environment_writes,
+    };
+    Ok((report, slots))
+}
+
+/// Reads the graph's boundary outputs from the slots of a completed pass.
+pub(super) fn boundary_outputs(
+    plan: &ExecutionPlan,
+    slots: &Slots,
+) -> Result<HashMap<String, RuntimeValue>, ExecError> {
     let mut outputs: HashMap<String, RuntimeValue> = HashMap::new();
-    for out in &graph.boundary_outputs {
-        if let Some(node_outs) = node_outputs.get(&out.maps_to.node_id) {
-            if let Some(val) = node_outs.get(&out.maps_to.port_name) {
+    for (out, slot) in &plan.boundary {
+        match &slots[*slot] {
+            Some(val) => {
                 outputs.insert(out.name.clone(), val.clone());
-            } else {
+            }
+            None => {
                 return Err(ExecError::MissingOutput {
                     node: out.maps_to.node_id.clone(),
                     output: out.maps_to.port_name.clone(),
                 });
             }
-        } else {
-            return Err(ExecError::MissingOutput {
-                node: out.maps_to.node_id.clone(),
-                output: out.maps_to.port_name.clone(),
-            });
         }
     }
+    Ok(outputs)
+}
 
-    Ok(ExecutionReport { outputs })
+/// Seeds state for every Compute node whose manifest declares `stateful: true`, and
+/// rejects context state addressed to any other node.
+pub(super) fn declared_compute_state(
+    plan: &ExecutionPlan,
+    registries: &Registries,
+    ctx: &ExecutionContext,
+) -> Result<HashMap<String, PrimitiveState>, ExecError> {
+    let mut state = HashMap::new();
+    for PlannedNode { node, .. } in &plan.nodes {
+        if node.kind != PrimitiveKind::Compute {
+            continue;
+        }
+        let primitive = registries
+            .computes
+            .get(&node.impl_id, &node.version)
+            .ok_or_else(|| ExecError::UnknownPrimitive {
+                id: node.impl_id.clone(),
+                version: node.version.clone(),
+            })?;
+        if primitive.manifest().state.stateful {
+            let seed = ctx
+                .compute_state
+                .get(&node.runtime_id)
+                .cloned()
+                .unwrap_or_default();
+            state.insert(node.runtime_id.clone(), seed);
+        }
+    }
+
+    if let Some(node) = ctx
+        .compute_state
+        .keys()
+        .filter(|id| !state.contains_key(*id))
+        .min()
+    {
+        return Err(ExecError::UndeclaredState { node: node.clone() });
+    }
+
+    Ok(state)
 }
 
-fn collect_inputs(
-    target: &str,
-    input_specs: &[crate::cluster::InputMetadata],
-    edges: &[ValidatedEdge],
-    node_outputs: &HashMap<String, HashMap<String, RuntimeValue>>,
+/// Builds a node's input map from its incoming slots. With `remaining` set, the last
+/// read of a slot that no boundary output needs moves the value instead of cloning it.
+pub(super) fn gather_inputs(
+    plan: &ExecutionPlan,
+    planned: &PlannedNode,
+    slots: &mut Slots,
+    mut remaining: Option<&mut [u32]>,
 ) -> Result<HashMap<String, RuntimeValue>, ExecError> {
-    let mut inputs: HashMap<String, RuntimeValue> = HashMap::new();
-
-    for edge in edges {
-        let Endpoint::NodePort {
-            node_id: to_node,
-            port_name: to_port,
-        } = &edge.to;
-        if to_node == target {
-            let Endpoint::NodePort {
-                node_id: from,
-                port_name: from_port,
-            } = &edge.from;
-            let outs = node_outputs
-                .get(from)
-                .ok_or_else(|| ExecError::MissingOutput {
-                    node: from.clone(),
-                    output: from_port.clone(),
-                })?;
-            let val = outs
-                .get(from_port)
-                .ok_or_else(|| ExecError::MissingOutput {
-                    node: from.clone(),
-                    output: from_port.clone(),
-                })?;
-            inputs.insert(to_port.clone(), val.clone());
-        }
+    if let Some(name) = &planned.unbound_required {
+        return Err(ExecError::MissingOutput {
+            node: planned.node.runtime_id.clone(),
+            output: name.clone(),
+        });
     }
 
-    // fill missing required
-    for spec in input_specs {
-        if spec.required && !inputs.contains_key(&spec.name) {
-            return Err(ExecError::MissingOutput {
-                node: target.to_string(),
-                output: spec.name.clone(),
-            });
+    let mut inputs: HashMap<String, RuntimeValue> = HashMap::with_capacity(planned.inputs.len());
+    for (port, slot) in &planned.inputs {
+        let slot = *slot;
+        let last_read = match remaining.as_deref_mut() {
+            Some(remaining) => {
+                remaining[slot] -= 1;
+                remaining[slot] == 0 && !plan.retained[slot]
+            }
+            None => false,
+        };
+        let val = if last_read {
+            slots[slot].take()
+        } else {
+            slots[slot].clone()
+        };
+        let val = val.ok_or_else(|| plan.missing_output(slot))?;
+        if planned.multiple.contains(port) {
+            match inputs
+                .entry(port.clone())
+                .or_insert_with(|| RuntimeValue::List(Vec::new()))
+            {
+                RuntimeValue::List(items) => items.push(val),
+                _ => unreachable!("multiple inputs are gathered as lists"),
+            }
+        } else {
+            inputs.insert(port.clone(), val);
         }
     }
-
     Ok(inputs)
 }
 
-fn execute_source(
+/// Writes a primitive's outputs into the node's slots; ports outside the plan are dropped.
+pub(super) fn store_outputs(
+    planned: &PlannedNode,
+    mut outputs: HashMap<String, RuntimeValue>,
+    slots: &mut Slots,
+) {
+    for (port, slot) in planned.ports.iter().zip(planned.slots.clone()) {
+        slots[slot] = outputs.remove(port);
+    }
+}
+
+/// The outputs a node produced, read back from its slots.
+pub(super) fn produced_outputs(
+    planned: &PlannedNode,
+    slots: &Slots,
+) -> HashMap<String, RuntimeValue> {
+    planned
+        .ports
+        .iter()
+        .zip(&slots[planned.slots.clone()])
+        .filter_map(|(port, val)| Some((port.clone(), val.clone()?)))
+        .collect()
+}
+
+pub(super) fn execute_source(
     node: &ValidatedNode,
     _inputs: HashMap<String, RuntimeValue>,
     registries: &Registries,
+    ctx: &ExecutionContext,
 ) -> Result<HashMap<String, RuntimeValue>, ExecError> {
-    let primitive =
-        registries
-            .sources
-            .get(&node.impl_id)
-            .ok_or_else(|| ExecError::UnknownPrimitive {
-                id: node.impl_id.clone(),
-                version: node.version.clone(),
-            })?;
-
-    let mut mapped_parameters: HashMap<String, crate::source::ParameterValue> = HashMap::new();
-    for (name, val) in &node.parameters {
-        let mapped = map_to_source_parameter_value(val).ok_or_else(|| {
-            ExecError::ParameterTypeConversionFailed {
-                node: node.runtime_id.clone(),
-                parameter: name.clone(),
-            }
+    let primitive = registries
+        .sources
+        .get(&node.impl_id, &node.version)
+        .ok_or_else(|| ExecError::UnknownPrimitive {
+            id: node.impl_id.clone(),
+            version: node.version.clone(),
         })?;
-        mapped_parameters.insert(name.clone(), mapped);
-    }
 
-    let outputs = primitive.produce(&mapped_parameters);
+    // Only the declared key is visible, so every read is one the host captured.
+    let environment: BTreeMap<String, Value> = primitive
+        .manifest()
+        .environment_key(&node.parameters)
+        .and_then(|key| ctx.environment.get_key_value(key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .into_iter()
+        .collect();
+    let source_ctx = SourceContext {
+        payload: &ctx.payload,
+        environment: &environment,
+    };
+    let outputs = isolate_panic(node, || {
+        primitive.try_produce(&node.parameters, &source_ctx)
+    })?
+    .map_err(|error| ExecError::SourceFailed {
+        node: node.runtime_id.clone(),
+        error,
+    })?;
     Ok(outputs
         .into_iter()
         .map(|(k, v)| (k, map_common_value(v)))
         .collect())
 }
 
-fn execute_compute(
+pub(super) fn execute_compute(
     node: &ValidatedNode,
     inputs: HashMap<String, RuntimeValue>,
-    registries: &Registries,
+    computes: &ComputeRegistry,
+    state: Option<&mut PrimitiveState>,
+    policy: NonFinitePolicy,
 ) -> Result<HashMap<String, RuntimeValue>, ExecError> {
     let primitive =
-        registries
-            .computes
-            .get(&node.impl_id)
+        computes
+            .get(&node.impl_id, &node.version)
             .ok_or_else(|| ExecError::UnknownPrimitive {
                 id: node.impl_id.clone(),
                 version: node.version.clone(),
@@ -166,38 +326,77 @@ fn execute_compute(
         mapped_inputs.insert(name, mapped);
     }
 
-    let mut mapped_parameters: HashMap<String, crate::common::Value> = HashMap::new();
-    for (name, val) in &node.parameters {
-        let mapped = map_to_compute_parameter_value(val).ok_or_else(|| {
-            ExecError::ParameterTypeConversionFailed {
-                node: node.runtime_id.clone(),
-                parameter: name.clone(),
+    let mapped_parameters: HashMap<String, crate::common::Value> = node
+        .parameters
+        .iter()
+        .map(|(name, val)| (name.clone(), map_to_compute_parameter_value(val)))
+        .collect();
+
+    let outputs = isolate_panic(node, || {
+        primitive.try_compute(&mapped_inputs, &mapped_parameters, state)
+    })?
+    .map_err(|error| ExecError::ComputeFailed {
+        node: node.runtime_id.clone(),
+        error,
+    })?;
+
+    // With several offending ports, name the first by port name so the error does not
+    // depend on map iteration order.
+    let mut mapped = HashMap::with_capacity(outputs.len());
+    let mut non_finite: Option<String> = None;
+    for (port, value) in outputs {
+        match apply_non_finite_policy(map_common_value(value), policy) {
+            Some(value) => {
+                mapped.insert(port, value);
             }
-        })?;
-        mapped_parameters.insert(name.clone(), mapped);
+            None => {
+                if non_finite.as_ref().is_none_or(|first| port < *first) {
+                    non_finite = Some(port);
+                }
+            }
+        }
     }
+    match non_finite {
+        Some(port) => Err(ExecError::NonFiniteValue {
+            node: node.runtime_id.clone(),
+            port,
+        }),
+        None => Ok(mapped),
+    }
+}
 
-    let outputs = primitive.compute(&mapped_inputs, &mapped_parameters, None);
-    Ok(outputs
-        .into_iter()
-        .map(|(k, v)| (k, map_common_value(v)))
-        .collect())
+/// `None` when `policy` is `Error` and the value holds a non-finite number.
+fn apply_non_finite_policy(value: RuntimeValue, policy: NonFinitePolicy) -> Option<RuntimeValue> {
+    let fix = |n: f64| match policy {
+        _ if n.is_finite() => Some(n),
+        NonFinitePolicy::Propagate => Some(n),
+        NonFinitePolicy::Error => None,
+        NonFinitePolicy::Substitute(substitute) => Some(substitute.get()),
+    };
+    match value {
+        RuntimeValue::Number(n) => fix(n).map(RuntimeValue::Number),
+        RuntimeValue::Series(values) => values
+            .into_iter()
+            .map(fix)
+            .collect::<Option<_>>()
+            .map(RuntimeValue::Series),
+        other => Some(other),
+    }
 }
 
-fn execute_trigger(
+pub(super) fn execute_trigger(
     node: &ValidatedNode,
     inputs: HashMap<String, RuntimeValue>,
     registries: &Registries,
     state: &mut HashMap<String, TriggerState>,
 ) -> Result<HashMap<String, RuntimeValue>, ExecError> {
-    let primitive =
-        registries
-            .triggers
-            .get(&node.impl_id)
-            .ok_or_else(|| ExecError::UnknownPrimitive {
-                id: node.impl_id.clone(),
-                version: node.version.clone(),
-            })?;
+    let primitive = registries
+        .triggers
+        .get(&node.impl_id, &node.version)
+        .ok_or_else(|| ExecError::UnknownPrimitive {
+            id: node.impl_id.clone(),
+            version: node.version.clone(),
+        })?;
 
     let mut mapped_inputs: HashMap<String, TriggerValue> = HashMap::new();
     for (name, val) in inputs {
@@ -208,38 +407,34 @@ fn execute_trigger(
         mapped_inputs.insert(name, mapped);
     }
 
-    let mut mapped_parameters: HashMap<String, crate::trigger::ParameterValue> = HashMap::new();
-    for (name, val) in &node.parameters {
-        let mapped = map_to_trigger_parameter_value(val).ok_or_else(|| {
-            ExecError::ParameterTypeConversionFailed {
-                node: node.runtime_id.clone(),
-                parameter: name.clone(),
-            }
-        })?;
-        mapped_parameters.insert(name.clone(), mapped);
-    }
-
     let node_state = state.entry(node.runtime_id.clone()).or_default();
-    let outputs = primitive.evaluate(&mapped_inputs, &mapped_parameters, Some(node_state));
+    let outputs = isolate_panic(node, || {
+        primitive.try_evaluate(&mapped_inputs, &node.parameters, Some(node_state))
+    })?
+    .map_err(|error| ExecError::TriggerFailed {
+        node: node.runtime_id.clone(),
+        error,
+    })?;
     Ok(outputs
         .into_iter()
         .map(|(k, v)| (k, map_trigger_value(v)))
         .collect())
 }
 
-fn execute_action(
+/// Runs an Action, adding any environment writes it makes to `environment_writes`.
+pub(super) fn execute_action(
     node: &ValidatedNode,
     inputs: HashMap<String, RuntimeValue>,
     registries: &Registries,
+    environment_writes: &mut BTreeMap<String, Value>,
 ) -> Result<HashMap<String, RuntimeValue>, ExecError> {
-    let primitive =
-        registries
-            .actions
-            .get(&node.impl_id)
-            .ok_or_else(|| ExecError::UnknownPrimitive {
-                id: node.impl_id.clone(),
-                version: node.version.clone(),
-            })?;
+    let primitive = registries
+        .actions
+        .get(&node.impl_id, &node.version)
+        .ok_or_else(|| ExecError::UnknownPrimitive {
+            id: node.impl_id.clone(),
+            version: node.version.clone(),
+        })?;
 
     let mut mapped_inputs: HashMap<String, ActionValue> = HashMap::new();
     for (name, val) in inputs {
@@ -247,29 +442,49 @@ fn execute_action(
         mapped_inputs.insert(name, mapped);
     }
 
-    let mut mapped_parameters: HashMap<String, crate::action::ParameterValue> = HashMap::new();
-    for (name, val) in &node.parameters {
-        let mapped = map_to_action_parameter_value(val).ok_or_else(|| {
-            ExecError::ParameterTypeConversionFailed {
-                node: node.runtime_id.clone(),
-                parameter: name.clone(),
-            }
-        })?;
-        mapped_parameters.insert(name.clone(), mapped);
-    }
-
-    let outputs = primitive.execute(&mapped_inputs, &mapped_parameters);
+    let mut action_ctx = ActionContext::default();
+    let outputs = isolate_panic(node, || {
+        primitive.try_execute(&mapped_inputs, &node.parameters, &mut action_ctx)
+    })?
+    .map_err(|error| ExecError::ActionFailed {
+        node: node.runtime_id.clone(),
+        error,
+    })?;
+    environment_writes.append(&mut action_ctx.environment_writes);
     Ok(outputs
         .into_iter()
         .map(|(k, v)| (k, map_action_value(v)))
         .collect())
 }
 
+/// Runs a primitive call, turning a panic into `ExecError::PrimitivePanicked`
+/// so one misbehaving primitive cannot unwind through the runtime and its host.
+fn isolate_panic<T>(node: &ValidatedNode, call: impl FnOnce() -> T) -> Result<T, ExecError> {
+    panic::catch_unwind(AssertUnwindSafe(call)).map_err(|payload| {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "non-string panic payload".to_string()
+        };
+        ExecError::PrimitivePanicked {
+            node: node.runtime_id.clone(),
+            impl_id: node.impl_id.clone(),
+            message,
+        }
+    })
+}
+
 fn map_common_value(v: crate::common::Value) -> RuntimeValue {
     match v {
         crate::common::Value::Number(n) => RuntimeValue::Number(n),
         crate::common::Value::Series(s) => RuntimeValue::Series(s),
         crate::common::Value::Bool(b) => RuntimeValue::Bool(b),
+        crate::common::Value::String(s) => RuntimeValue::String(s),
+        crate::common::Value::List(items) => {
+            RuntimeValue::List(items.into_iter().map(map_common_value).collect())
+        }
     }
 }
 
@@ -278,18 +493,25 @@ fn map_to_compute_value(v: &RuntimeValue) -> Option<crate::common::Value> {
         RuntimeValue::Number(n) => Some(crate::common::Value::Number(*n)),
         RuntimeValue::Series(s) => Some(crate::common::Value::Series(s.clone())),
         RuntimeValue::Bool(b) => Some(crate::common::Value::Bool(*b)),
+        RuntimeValue::String(s) => Some(crate::common::Value::String(s.clone())),
+        RuntimeValue::List(items) => items
+            .iter()
+            .map(map_to_compute_value)
+            .collect::<Option<_>>()
+            .map(crate::common::Value::List),
         _ => None,
     }
 }
 
-fn map_to_compute_parameter_value(
-    v: &crate::cluster::ParameterValue,
-) -> Option<crate::common::Value> {
+/// Compute primitives see an Int as a Number and an Enum as the String naming its variant.
+fn map_to_compute_parameter_value(v: &crate::cluster::ParameterValue) -> crate::common::Value {
     match v {
-        crate::cluster::ParameterValue::Int(i) => Some(crate::common::Value::Number(*i as f64)),
-        crate::cluster::ParameterValue::Number(n) => Some(crate::common::Value::Number(*n)),
-        crate::cluster::ParameterValue::Bool(b) => Some(crate::common::Value::Bool(*b)),
-        _ => None,
+        crate::cluster::ParameterValue::Int(i) => crate::common::Value::Number(*i as f64),
+        crate::cluster::ParameterValue::Number(n) => crate::common::Value::Number(*n),
+        crate::cluster::ParameterValue::Bool(b) => crate::common::Value::Bool(*b),
+        crate::cluster::ParameterValue::String(s) | crate::cluster::ParameterValue::Enum(s) => {
+            crate::common::Value::String(s.clone())
+        }
     }
 }
 
@@ -299,6 +521,9 @@ fn map_trigger_value(v: TriggerValue) -> RuntimeValue {
         TriggerValue::Series(s) => RuntimeValue::Series(s),
         TriggerValue::Bool(b) => RuntimeValue::Bool(b),
         TriggerValue::Event(e) => RuntimeValue::Event(RuntimeEvent::Trigger(e)),
+        TriggerValue::List(items) => {
+            RuntimeValue::List(items.into_iter().map(map_trigger_value).collect())
+        }
     }
 }
 
@@ -308,28 +533,15 @@ fn map_to_trigger_value(v: &RuntimeValue) -> Option<TriggerValue> {
         RuntimeValue::Series(s) => Some(TriggerValue::Series(s.clone())),
         RuntimeValue::Bool(b) => Some(TriggerValue::Bool(*b)),
         RuntimeValue::Event(RuntimeEvent::Trigger(e)) => Some(TriggerValue::Event(e.clone())),
+        RuntimeValue::List(items) => items
+            .iter()
+            .map(map_to_trigger_value)
+            .collect::<Option<_>>()
+            .map(TriggerValue::List),
         _ => None,
     }
 }
 
-fn map_to_trigger_parameter_value(
-    v: &crate::cluster::ParameterValue,
-) -> Option<crate::trigger::ParameterValue> {
-    match v {
-        crate::cluster::ParameterValue::Int(i) => Some(crate::trigger::ParameterValue::Int(*i)),
-        crate::cluster::ParameterValue::Number(n) => {
-            Some(crate::trigger::ParameterValue::Number(*n))
-        }
-        crate::cluster::ParameterValue::Bool(b) => Some(crate::trigger::ParameterValue::Bool(*b)),
-        crate::cluster::ParameterValue::String(s) => {
-            Some(crate::trigger::ParameterValue::String(s.clone()))
-        }
-        crate::cluster::ParameterValue::Enum(e) => {
-            Some(crate::trigger::ParameterValue::Enum(e.clone()))
-        }
-    }
-}
-
 fn map_action_value(v: ActionValue) -> RuntimeValue {
     match v {
         ActionValue::Event(e) => RuntimeValue::Event(RuntimeEvent::Action(e)),
@@ -355,59 +567,32 @@ fn map_to_action_value(v: &RuntimeValue, node: &str, port: &str) -> Result<Actio
     }
 }
 
-fn map_to_action_parameter_value(
-    v: &crate::cluster::ParameterValue,
-) -> Option<crate::action::ParameterValue> {
-    match v {
-        crate::cluster::ParameterValue::Int(i) => Some(crate::action::ParameterValue::Int(*i)),
-        crate::cluster::ParameterValue::Number(n) => {
-            Some(crate::action::ParameterValue::Number(*n))
-        }
-        crate::cluster::ParameterValue::Bool(b) => Some(crate::action::ParameterValue::Bool(*b)),
-        crate::cluster::ParameterValue::String(s) => {
-            Some(crate::action::ParameterValue::String(s.clone()))
-        }
-        crate::cluster::ParameterValue::Enum(e) => {
-            Some(crate::action::ParameterValue::Enum(e.clone()))
-        }
-    }
-}
-
-fn map_to_source_parameter_value(
-    v: &crate::cluster::ParameterValue,
-) -> Option<crate::source::ParameterValue> {
-    match v {
-        crate::cluster::ParameterValue::Int(i) => Some(crate::source::ParameterValue::Int(*i)),
-        crate::cluster::ParameterValue::Number(n) => {
-            Some(crate::source::ParameterValue::Number(*n))
-        }
-        crate::cluster::ParameterValue::Bool(b) => Some(crate::source::ParameterValue::Bool(*b)),
-        crate::cluster::ParameterValue::String(s) => {
-            Some(crate::source::ParameterValue::String(s.clone()))
-        }
-        crate::cluster::ParameterValue::Enum(e) => {
-            Some(crate::source::ParameterValue::Enum(e.clone()))
-        }
-    }
-}
-
 /// R.7 gating: Returns true if any Event input is TriggerEvent::NotEmitted.
 /// Uses AND semantics: all trigger events must be Emitted for action to execute.
-fn should_skip_action(inputs: &HashMap<String, RuntimeValue>) -> bool {
-    inputs.values().any(|v| {
+/// A `Multiple` event input arrives as a list and counts as Emitted when at least
+/// one of its events is.
+pub(super) fn should_skip_action(inputs: &HashMap<String, RuntimeValue>) -> bool {
+    let emitted = |v: &RuntimeValue| {
         matches!(
             v,
-            RuntimeValue::Event(RuntimeEvent::Trigger(TriggerEvent::NotEmitted))
+            RuntimeValue::Event(RuntimeEvent::Trigger(TriggerEvent::Emitted))
         )
+    };
+    inputs.values().any(|v| match v {
+        RuntimeValue::Event(RuntimeEvent::Trigger(TriggerEvent::NotEmitted)) => true,
+        RuntimeValue::List(items) if matches!(items.first(), Some(RuntimeValue::Event(_))) => {
+            !items.iter().any(emitted)
+        }
+        _ => false,
     })
 }
 
 /// Produce outputs for a skipped action. Event outputs get ActionOutcome::Skipped.
-fn produce_skipped_outputs(node: &ValidatedNode) -> HashMap<String, RuntimeValue> {
+pub(super) fn produce_skipped_outputs(node: &ValidatedNode) -> HashMap<String, RuntimeValue> {
     node.outputs
         .iter()
         .map(|(name, meta)| {
-            let value = match meta.value_type {
+            let value = match &meta.value_type {
                 ValueType::Event => {
                     RuntimeValue::Event(RuntimeEvent::Action(ActionOutcome::Skipped))
                 }
@@ -416,6 +601,10 @@ fn produce_skipped_outputs(node: &ValidatedNode) -> HashMap<String, RuntimeValue
                 ValueType::Bool => RuntimeValue::Bool(false),
                 ValueType::String => RuntimeValue::String(String::new()),
                 ValueType::Series => RuntimeValue::Series(vec![]),
+                // Action manifests have no type variables.
+                ValueType::Var(variable) => {
+                    unreachable!("action output typed by variable '{}'", variable)
+                }
             };
             (name.clone(), value)
         })
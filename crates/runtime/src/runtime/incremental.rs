@@ -0,0 +1,64 @@
+//! Incremental re-execution across passes of one validated graph.
+//!
+//! The executor keeps the node outputs of its last successful pass. On the next pass,
+//! Sources run as usual and a stateless Compute or Trigger node is re-evaluated only
+//! when one of its upstream nodes produced different outputs; otherwise its previous
+//! outputs are reused. Stateful Computes always run so their declared state advances
+//! once per pass, and Actions always run (subject to R.7 gating) so every pass still
+//! executes each Action exactly once.
+//!
+//! Reuse is observationally equivalent to `execute`: the report, including the trace,
+//! is the same as a full pass would produce given deterministic primitives (X.4).
+
+use super::execute::execute_pass;
+use super::plan::{ExecutionPlan, Slots};
+use super::types::{
+    ExecError, ExecutionContext, ExecutionReport, NonFinitePolicy, Registries, ValidatedGraph,
+};
+
+pub struct IncrementalExecutor {
+    graph: ValidatedGraph,
+    plan: ExecutionPlan,
+    /// Slots of the last successful pass; empty until the first one.
+    previous: Slots,
+    /// Policy those slots were produced under; a pass under another policy reuses
+    /// nothing.
+    non_finite: NonFinitePolicy,
+}
+
+impl IncrementalExecutor {
+    pub fn new(graph: ValidatedGraph) -> Self {
+        let plan = ExecutionPlan::compile(&graph);
+        Self {
+            graph,
+            plan,
+            previous: Slots::new(),
+            non_finite: NonFinitePolicy::default(),
+        }
+    }
+
+    pub fn graph(&self) -> &ValidatedGraph {
+        &self.graph
+    }
+
+    /// Runs one pass, reusing outputs from the last successful pass where possible.
+    /// A failed pass leaves the previous outputs untouched.
+    pub fn execute(
+        &mut self,
+        registries: &Registries,
+        ctx: &ExecutionContext,
+    ) -> Result<ExecutionReport, ExecError> {
+        if ctx.non_finite != self.non_finite {
+            self.previous.clear();
+        }
+        let (report, slots) = execute_pass(&self.plan, registries, ctx, Some(&self.previous))?;
+        self.previous = slots;
+        self.non_finite = ctx.non_finite;
+        Ok(report)
+    }
+
+    /// Forgets the previous pass so the next one evaluates every node.
+    pub fn reset(&mut self) {
+        self.previous.clear();
+    }
+}
@@ -1,15 +1,24 @@
 pub mod execute;
+pub mod incremental;
+pub mod parallel;
+pub mod plan;
 pub mod types;
 pub mod validate;
 
-pub use execute::execute;
+pub use execute::{execute, execute_plan};
+pub use incremental::IncrementalExecutor;
+pub use parallel::ParallelExecutor;
+pub use plan::ExecutionPlan;
 pub use types::*;
-pub use validate::validate;
+pub use validate::{validate, validate_all};
 
 #[cfg(test)]
 mod tests;
 
+use std::collections::BTreeSet;
+
 use crate::cluster::{ExpandedGraph, PrimitiveCatalog};
+use crate::source::SourceRegistry;
 
 #[derive(Debug)]
 pub enum RuntimeError {
@@ -28,3 +37,19 @@ pub fn run<C: PrimitiveCatalog>(
     let validated = validate(expanded, catalog).map_err(RuntimeError::Validation)?;
     execute(&validated, registries, ctx).map_err(RuntimeError::Execution)
 }
+
+/// Environment keys the graph's sources read, as their manifests declare through
+/// `environment_key_parameter`. A host stamps each event with these keys before running it.
+pub fn environment_keys(expanded: &ExpandedGraph, sources: &SourceRegistry) -> BTreeSet<String> {
+    expanded
+        .nodes
+        .values()
+        .filter_map(|node| {
+            let source = sources.get(&node.implementation.impl_id, &node.implementation.version)?;
+            source
+                .manifest()
+                .environment_key(&node.parameters)
+                .map(str::to_string)
+        })
+        .collect()
+}
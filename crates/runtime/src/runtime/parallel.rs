@@ -0,0 +1,316 @@
+//! Level-parallel evaluation of a validated graph.
+//!
+//! Nodes are grouped into dependency levels: a node's level is one more than the
+//! highest level among its upstream nodes. Levels run in order. Within a level the
+//! Compute nodes are independent and are evaluated on the executor's worker pool;
+//! Sources and Triggers run on the calling thread. Actions are terminal (F.2), so they run
+//! after every level, sequentially in `topo_order` as execution_model.md §7 requires.
+//!
+//! The result is identical to `execute`. On failure, the error is the one `execute`
+//! would have returned: the failing node earliest in `topo_order`, with only the
+//! Actions that precede it executed.
+
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::thread;
+
+use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+
+use crate::cluster::PrimitiveKind;
+use crate::compute::{PrimitiveRegistry as ComputeRegistry, PrimitiveState};
+
+use super::execute::{
+    boundary_outputs, declared_compute_state, execute_action, execute_compute, execute_source,
+    execute_trigger, gather_inputs, produce_skipped_outputs, produced_outputs, should_skip_action,
+    store_outputs,
+};
+use super::plan::{ExecutionPlan, PlannedNode, Slots};
+use super::types::{
+    ExecError, ExecutionContext, ExecutionReport, NodeTrace, Registries, RuntimeValue,
+    ValidatedGraph, ValidatedNode,
+};
+
+pub struct ParallelExecutor {
+    graph: ValidatedGraph,
+    plan: ExecutionPlan,
+    /// Plan node indices by dependency level; each level keeps `topo_order` order.
+    levels: Vec<Vec<usize>>,
+    /// Created once and reused by every `execute` call.
+    pool: ThreadPool,
+}
+
+struct ComputeTask<'a> {
+    index: usize,
+    node: &'a ValidatedNode,
+    inputs: HashMap<String, RuntimeValue>,
+    state: Option<PrimitiveState>,
+}
+
+type ComputeOutcome<'a> = (
+    ComputeTask<'a>,
+    Result<HashMap<String, RuntimeValue>, ExecError>,
+);
+
+/// What the trace needs from a node, captured when it runs.
+#[derive(Default)]
+struct Traced {
+    inputs: HashMap<String, RuntimeValue>,
+    outputs: HashMap<String, RuntimeValue>,
+    skipped: bool,
+}
+
+impl ParallelExecutor {
+    /// Uses one worker per available CPU.
+    pub fn new(graph: ValidatedGraph) -> Result<Self, ThreadPoolBuildError> {
+        let workers = thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1);
+        Self::with_workers(graph, workers)
+    }
+
+    /// Starts a pool of `workers` threads (at least one) that lives as long as the executor.
+    /// Fails if the operating system cannot spawn the worker threads.
+    pub fn with_workers(
+        graph: ValidatedGraph,
+        workers: usize,
+    ) -> Result<Self, ThreadPoolBuildError> {
+        let plan = ExecutionPlan::compile(&graph);
+
+        let mut depth: Vec<usize> = Vec::with_capacity(plan.node_count());
+        let mut levels: Vec<Vec<usize>> = Vec::new();
+        for (index, planned) in plan.nodes.iter().enumerate() {
+            let level = planned
+                .upstream
+                .iter()
+                .map(|&u| depth[u] + 1)
+                .max()
+                .unwrap_or(0);
+            depth.push(level);
+            if levels.len() <= level {
+                levels.resize_with(level + 1, Vec::new);
+            }
+            levels[level].push(index);
+        }
+
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(workers.max(1))
+            .thread_name(|i| format!("ergo-compute-{i}"))
+            .build()?;
+
+        Ok(Self {
+            graph,
+            plan,
+            levels,
+            pool,
+        })
+    }
+
+    pub fn workers(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    pub fn graph(&self) -> &ValidatedGraph {
+        &self.graph
+    }
+
+    /// Node ids by dependency level.
+    pub fn levels(&self) -> Vec<Vec<&str>> {
+        self.levels
+            .iter()
+            .map(|level| {
+                level
+                    .iter()
+                    .map(|&i| self.plan.nodes[i].node.runtime_id.as_str())
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn execute(
+        &self,
+        registries: &Registries,
+        ctx: &ExecutionContext,
+    ) -> Result<ExecutionReport, ExecError> {
+        let plan = &self.plan;
+        let mut slots: Slots = vec![None; plan.slot_count()];
+        let mut remaining = plan.consumers.clone();
+        let mut trigger_state = ctx.trigger_state.clone();
+        let mut compute_state = declared_compute_state(plan, registries, ctx)?;
+        let mut traced: Vec<Traced> = Vec::new();
+        let mut environment_writes = BTreeMap::new();
+        if ctx.trace {
+            traced.resize_with(plan.node_count(), Traced::default);
+        }
+
+        // Failures keyed by plan index, which is topo position. Nodes downstream of a
+        // failure are not evaluated, so the first entry is the error a sequential pass
+        // would have stopped at.
+        let mut failures: BTreeMap<usize, ExecError> = BTreeMap::new();
+        let mut failed = vec![false; plan.node_count()];
+
+        for level in &self.levels {
+            let mut tasks: Vec<ComputeTask> = Vec::new();
+
+            for &index in level {
+                let planned = &plan.nodes[index];
+                let node = &planned.node;
+                if node.kind == PrimitiveKind::Action {
+                    continue;
+                }
+                if planned.upstream.iter().any(|&u| failed[u]) {
+                    failed[index] = true;
+                    continue;
+                }
+
+                let result = gather_inputs(plan, planned, &mut slots, Some(&mut remaining))
+                    .and_then(|inputs| {
+                        if ctx.trace {
+                            traced[index].inputs = inputs.clone();
+                        }
+                        match node.kind {
+                            PrimitiveKind::Compute => {
+                                tasks.push(ComputeTask {
+                                    index,
+                                    node,
+                                    inputs,
+                                    state: compute_state.remove(&node.runtime_id),
+                                });
+                                Ok(None)
+                            }
+                            PrimitiveKind::Source => {
+                                execute_source(node, inputs, registries, ctx).map(Some)
+                            }
+                            PrimitiveKind::Trigger => {
+                                execute_trigger(node, inputs, registries, &mut trigger_state)
+                                    .map(Some)
+                            }
+                            PrimitiveKind::Action => unreachable!("actions are deferred"),
+                        }
+                    });
+                match result {
+                    Ok(Some(outputs)) => {
+                        settle(planned, outputs, &mut slots, traced.get_mut(index));
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        failures.insert(index, e);
+                        failed[index] = true;
+                    }
+                }
+            }
+
+            let outcomes = evaluate_computes(tasks, registries.computes, ctx, &self.pool);
+            for (task, result) in outcomes {
+                if let Some(state) = task.state {
+                    compute_state.insert(task.node.runtime_id.clone(), state);
+                }
+                match result {
+                    Ok(outputs) => {
+                        let planned = &plan.nodes[task.index];
+                        settle(planned, outputs, &mut slots, traced.get_mut(task.index));
+                    }
+                    Err(e) => {
+                        failures.insert(task.index, e);
+                        failed[task.index] = true;
+                    }
+                }
+            }
+        }
+
+        // §7: Actions run sequentially in topological order, and only those a sequential
+        // pass would have reached before its first failure.
+        let cutoff = failures.keys().next().copied().unwrap_or(usize::MAX);
+        for (index, planned) in plan.nodes.iter().enumerate().take(cutoff) {
+            let node = &planned.node;
+            if node.kind != PrimitiveKind::Action {
+                continue;
+            }
+            let inputs = gather_inputs(plan, planned, &mut slots, Some(&mut remaining))?;
+            if ctx.trace {
+                traced[index].inputs = inputs.clone();
+            }
+            // R.7: Actions execute only when all trigger event inputs are Emitted.
+            let outputs = if should_skip_action(&inputs) {
+                if ctx.trace {
+                    traced[index].skipped = true;
+                }
+                produce_skipped_outputs(node)
+            } else {
+                execute_action(node, inputs, registries, &mut environment_writes)?
+            };
+            settle(planned, outputs, &mut slots, traced.get_mut(index));
+        }
+
+        if let Some((_, error)) = failures.into_iter().next() {
+            return Err(error);
+        }
+
+        let trace = ctx.trace.then(|| {
+            plan.nodes
+                .iter()
+                .zip(traced)
+                .map(|(planned, traced)| {
+                    let node = &planned.node;
+                    NodeTrace {
+                        node: node.runtime_id.clone(),
+                        impl_id: node.impl_id.clone(),
+                        version: node.version.clone(),
+                        kind: node.kind.clone(),
+                        inputs: traced.inputs,
+                        parameters: node.parameters.clone(),
+                        outputs: traced.outputs,
+                        skipped: traced.skipped,
+                    }
+                })
+                .collect()
+        });
+
+        Ok(ExecutionReport {
+            outputs: boundary_outputs(plan, &slots)?,
+            trace,
+            compute_state,
+            environment_writes,
+        })
+    }
+}
+
+/// Stores a node's outputs, recording them for the trace before any later read moves them.
+fn settle(
+    planned: &PlannedNode,
+    outputs: HashMap<String, RuntimeValue>,
+    slots: &mut Slots,
+    traced: Option<&mut Traced>,
+) {
+    store_outputs(planned, outputs, slots);
+    if let Some(traced) = traced {
+        traced.outputs = produced_outputs(planned, slots);
+    }
+}
+
+/// Evaluates one level's Compute nodes on the pool. Outcomes come back in task order
+/// regardless of which worker finishes first.
+fn evaluate_computes<'a>(
+    tasks: Vec<ComputeTask<'a>>,
+    computes: &ComputeRegistry,
+    ctx: &ExecutionContext,
+    pool: &ThreadPool,
+) -> Vec<ComputeOutcome<'a>> {
+    let run = |mut task: ComputeTask<'a>| -> ComputeOutcome<'a> {
+        let inputs = std::mem::take(&mut task.inputs);
+        let result = execute_compute(
+            task.node,
+            inputs,
+            computes,
+            task.state.as_mut(),
+            ctx.non_finite,
+        );
+        (task, result)
+    };
+
+    if pool.current_num_threads() == 1 || tasks.len() < 2 {
+        return tasks.into_iter().map(run).collect();
+    }
+
+    pool.install(|| tasks.into_par_iter().map(run).collect())
+}
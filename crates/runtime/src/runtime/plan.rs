@@ -0,0 +1,165 @@
+//! Execution plan compiled once from a `ValidatedGraph`.
+//!
+//! Nodes are indexed by their position in `topo_order` and every output port is
+//! interned to a slot in a flat table. Each node carries its precomputed incoming
+//! edges as (input port, slot) pairs, so a pass gathers inputs without scanning the
+//! edge list or looking up nested string-keyed maps.
+
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
+
+use crate::cluster::Cardinality;
+
+use super::types::{
+    Endpoint, ExecError, RuntimeValue, ValidatedGraph, ValidatedNode, ValidatedOutput,
+};
+
+/// Flat output storage for one pass, indexed by slot.
+pub(super) type Slots = Vec<Option<RuntimeValue>>;
+
+#[derive(Debug, Clone)]
+pub struct ExecutionPlan {
+    /// Nodes in topological order; a node's index is its position here.
+    pub(super) nodes: Vec<PlannedNode>,
+    /// Owning node index and port name of every slot.
+    pub(super) slot_ports: Vec<(usize, String)>,
+    /// Number of edges reading each slot.
+    pub(super) consumers: Vec<u32>,
+    /// Slots read by a boundary output; their values must survive the pass.
+    pub(super) retained: Vec<bool>,
+    /// Boundary outputs with the slot each reads.
+    pub(super) boundary: Vec<(ValidatedOutput, usize)>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct PlannedNode {
+    pub(super) node: ValidatedNode,
+    /// Slot range holding this node's outputs, one slot per port in `ports` order.
+    pub(super) slots: Range<usize>,
+    pub(super) ports: Vec<String>,
+    /// Incoming edges as (input port, source slot), in edge order.
+    pub(super) inputs: Vec<(String, usize)>,
+    /// Input ports declared `Cardinality::Multiple`; their values are gathered into a
+    /// `RuntimeValue::List` in edge order.
+    pub(super) multiple: Vec<String>,
+    /// Distinct upstream node indices.
+    pub(super) upstream: Vec<usize>,
+    /// A required input with no incoming edge; reported when the node is reached.
+    pub(super) unbound_required: Option<String>,
+}
+
+impl ExecutionPlan {
+    pub fn compile(graph: &ValidatedGraph) -> Self {
+        let index: HashMap<String, usize> = graph
+            .topo_order
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.clone(), i))
+            .collect();
+
+        // Every declared output gets a slot, plus any port an edge names, so a missing
+        // output surfaces as ExecError::MissingOutput at run time. Boundary outputs were
+        // checked against the declared outputs during validation.
+        let mut ports: Vec<BTreeSet<String>> = graph
+            .topo_order
+            .iter()
+            .map(|id| graph.nodes[id].outputs.keys().cloned().collect())
+            .collect();
+        for edge in &graph.edges {
+            let Endpoint::NodePort { node_id, port_name } = &edge.from;
+            ports[index[node_id]].insert(port_name.clone());
+        }
+
+        let mut slot_ports = Vec::new();
+        let mut nodes: Vec<PlannedNode> = Vec::with_capacity(ports.len());
+        for (i, node_ports) in ports.into_iter().enumerate() {
+            let first = slot_ports.len();
+            slot_ports.extend(node_ports.iter().map(|p| (i, p.clone())));
+            let node = graph.nodes[&graph.topo_order[i]].clone();
+            let multiple = node
+                .inputs
+                .iter()
+                .filter(|input| input.cardinality == Cardinality::Multiple)
+                .map(|input| input.name.clone())
+                .collect();
+            nodes.push(PlannedNode {
+                node,
+                slots: first..slot_ports.len(),
+                ports: node_ports.into_iter().collect(),
+                inputs: Vec::new(),
+                multiple,
+                upstream: Vec::new(),
+                unbound_required: None,
+            });
+        }
+
+        let slot_of = |nodes: &[PlannedNode], node: usize, port: &str| {
+            let planned = &nodes[node];
+            let offset = planned
+                .ports
+                .binary_search_by(|p| p.as_str().cmp(port))
+                .expect("port interned above");
+            planned.slots.start + offset
+        };
+
+        let mut consumers = vec![0; slot_ports.len()];
+        for edge in &graph.edges {
+            let Endpoint::NodePort {
+                node_id: from,
+                port_name: from_port,
+            } = &edge.from;
+            let Endpoint::NodePort {
+                node_id: to,
+                port_name: to_port,
+            } = &edge.to;
+            let from = index[from];
+            let slot = slot_of(&nodes, from, from_port);
+            consumers[slot] += 1;
+            let target = &mut nodes[index[to]];
+            target.inputs.push((to_port.clone(), slot));
+            if !target.upstream.contains(&from) {
+                target.upstream.push(from);
+            }
+        }
+
+        for planned in &mut nodes {
+            planned.unbound_required = planned
+                .node
+                .required_inputs()
+                .find(|spec| !planned.inputs.iter().any(|(port, _)| *port == spec.name))
+                .map(|spec| spec.name.clone());
+        }
+
+        let mut retained = vec![false; slot_ports.len()];
+        let mut boundary = Vec::new();
+        for out in &graph.boundary_outputs {
+            let slot = slot_of(&nodes, index[&out.maps_to.node_id], &out.maps_to.port_name);
+            retained[slot] = true;
+            boundary.push((out.clone(), slot));
+        }
+
+        Self {
+            nodes,
+            slot_ports,
+            consumers,
+            retained,
+            boundary,
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn slot_count(&self) -> usize {
+        self.slot_ports.len()
+    }
+
+    pub(super) fn missing_output(&self, slot: usize) -> ExecError {
+        let (node, port) = &self.slot_ports[slot];
+        ExecError::MissingOutput {
+            node: self.nodes[*node].node.runtime_id.clone(),
+            output: port.clone(),
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use std::collections::HashMap;
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use crate::action;
 use crate::catalog::{build_core_catalog, core_registries};
@@ -6,12 +10,19 @@ use crate::cluster::{
     ExpandedEndpoint, ExpandedGraph, ExpandedNode, InputMetadata, OutputMetadata, PrimitiveCatalog,
     PrimitiveKind, PrimitiveMetadata, ValueType,
 };
+use crate::common::Value;
 use crate::compute::implementations::{Add, ConstNumber};
 use crate::compute::PrimitiveRegistry as ComputeRegistry;
-use crate::runtime::run;
-use crate::runtime::types::{ExecutionContext, Registries, RuntimeValue};
+use crate::runtime::execute::should_skip_action;
+use crate::runtime::types::{
+    Diagnostic, ExecError, ExecutionContext, NonFinitePolicy, Registries, RuntimeEvent,
+    RuntimeValue, ValidationError,
+};
+use crate::runtime::{
+    execute_plan, run, ExecutionPlan, IncrementalExecutor, ParallelExecutor, RuntimeError,
+};
 use crate::source::{SourceKind, SourcePrimitive, SourcePrimitiveManifest, SourceRegistry};
-use crate::trigger::TriggerRegistry;
+use crate::trigger::{TriggerEvent, TriggerRegistry};
 
 #[derive(Default)]
 struct TestCatalog {
@@ -43,14 +54,17 @@ fn add_metadata() -> PrimitiveMetadata {
                 name: "a".to_string(),
                 value_type: ValueType::Number,
                 required: true,
+                cardinality: crate::cluster::Cardinality::Single,
             },
             InputMetadata {
                 name: "b".to_string(),
                 value_type: ValueType::Number,
                 required: true,
+                cardinality: crate::cluster::Cardinality::Single,
             },
         ],
         outputs,
+        parameters: Vec::new(),
     }
 }
 
@@ -68,6 +82,7 @@ fn source_metadata() -> PrimitiveMetadata {
         kind: PrimitiveKind::Source,
         inputs: Vec::new(),
         outputs,
+        parameters: Vec::new(),
     }
 }
 
@@ -96,6 +111,7 @@ impl ConstSource {
                 },
                 state: crate::source::StateSpec { allowed: false },
                 side_effects: false,
+                environment_key_parameter: None,
             },
             value,
         }
@@ -149,7 +165,7 @@ fn unified_runtime_executes_compute_graph() {
             authoring_path: vec![],
             implementation: crate::cluster::ImplementationInstance {
                 impl_id: "add".to_string(),
-                version: "v1".to_string(),
+                version: "0.1.0".to_string(),
             },
             parameters: HashMap::new(),
         },
@@ -194,7 +210,7 @@ fn unified_runtime_executes_compute_graph() {
     let mut catalog = TestCatalog::default();
     catalog
         .metadata
-        .insert(("add".to_string(), "v1".to_string()), add_metadata());
+        .insert(("add".to_string(), "0.1.0".to_string()), add_metadata());
     catalog
         .metadata
         .insert(("const1".to_string(), "v1".to_string()), source_metadata());
@@ -220,9 +236,7 @@ fn unified_runtime_executes_compute_graph() {
         actions: &crate::action::ActionRegistry::new(),
     };
 
-    let ctx = ExecutionContext {
-        trigger_state: HashMap::new(),
-    };
+    let ctx = ExecutionContext::default();
 
     let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
     assert_eq!(report.outputs.get("sum"), Some(&RuntimeValue::Number(7.0)));
@@ -273,6 +287,14 @@ fn parameters_flow_into_compute_execution() {
                     cardinality: crate::cluster::Cardinality::Single,
                 },
             )]),
+            parameters: vec![crate::cluster::ParameterSpec {
+                name: "value".to_string(),
+                ty: crate::cluster::ParameterType::Number,
+                default: None,
+                required: true,
+                bounds: None,
+                variants: Vec::new(),
+            }],
         },
     );
 
@@ -288,9 +310,7 @@ fn parameters_flow_into_compute_execution() {
         actions: &action::ActionRegistry::new(),
     };
 
-    let ctx = ExecutionContext {
-        trigger_state: HashMap::new(),
-    };
+    let ctx = ExecutionContext::default();
 
     let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
     assert_eq!(report.outputs.get("out"), Some(&RuntimeValue::Number(4.5)));
@@ -434,9 +454,7 @@ fn hello_world_graph_executes_with_core_catalog_and_registries() {
         actions: &registries.actions,
     };
 
-    let ctx = ExecutionContext {
-        trigger_state: HashMap::new(),
-    };
+    let ctx = ExecutionContext::default();
 
     let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
     assert_eq!(
@@ -725,9 +743,7 @@ fn r7_action_skipped_when_trigger_not_emitted() {
         actions: &registries.actions,
     };
 
-    let ctx = ExecutionContext {
-        trigger_state: HashMap::new(),
-    };
+    let ctx = ExecutionContext::default();
 
     let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
 
@@ -740,3 +756,2234 @@ fn r7_action_skipped_when_trigger_not_emitted() {
         "R.7: Action must return Skipped when gating trigger emits NotEmitted"
     );
 }
+
+/// R.7 with a `Multiple` event input: the action runs when any of its events
+/// was emitted and is skipped when none was.
+#[test]
+fn r7_multiple_event_input_needs_one_emitted_event() {
+    let trigger = |event| RuntimeValue::Event(RuntimeEvent::Trigger(event));
+    let events = |list: Vec<TriggerEvent>| {
+        HashMap::from([(
+            "events".to_string(),
+            RuntimeValue::List(list.into_iter().map(trigger).collect()),
+        )])
+    };
+
+    assert!(!should_skip_action(&events(vec![
+        TriggerEvent::NotEmitted,
+        TriggerEvent::Emitted,
+    ])));
+    assert!(should_skip_action(&events(vec![
+        TriggerEvent::NotEmitted,
+        TriggerEvent::NotEmitted,
+    ])));
+
+    // Ports are still ANDed: a NotEmitted single input skips the action regardless.
+    let mut inputs = events(vec![TriggerEvent::Emitted]);
+    inputs.insert("event".to_string(), trigger(TriggerEvent::NotEmitted));
+    assert!(should_skip_action(&inputs));
+
+    // Lists of other values never gate.
+    let numbers = HashMap::from([(
+        "values".to_string(),
+        RuntimeValue::List(vec![RuntimeValue::Number(1.0)]),
+    )]);
+    assert!(!should_skip_action(&numbers));
+}
+
+fn impl_node(id: &str, impl_id: &str, version: &str) -> ExpandedNode {
+    ExpandedNode {
+        runtime_id: id.to_string(),
+        authoring_path: vec![],
+        implementation: crate::cluster::ImplementationInstance {
+            impl_id: impl_id.to_string(),
+            version: version.to_string(),
+        },
+        parameters: HashMap::new(),
+    }
+}
+
+fn port_edge(from: &str, output: &str, to: &str, input: &str) -> crate::cluster::ExpandedEdge {
+    crate::cluster::ExpandedEdge {
+        from: ExpandedEndpoint::NodePort {
+            node_id: from.to_string(),
+            port_name: output.to_string(),
+        },
+        to: ExpandedEndpoint::NodePort {
+            node_id: to.to_string(),
+            port_name: input.to_string(),
+        },
+    }
+}
+
+#[test]
+fn validate_all_reports_every_failure_with_location() {
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            (
+                "src".to_string(),
+                impl_node("src", "number_source", "0.1.0"),
+            ),
+            ("gt1".to_string(), impl_node("gt1", "gt", "0.1.0")),
+            ("ghost".to_string(), impl_node("ghost", "nope", "0.1.0")),
+            ("act".to_string(), impl_node("act", "ack_action", "0.1.0")),
+        ]),
+        edges: vec![
+            port_edge("src", "value", "gt1", "a"),
+            port_edge("src", "value", "gt1", "typo"),
+            port_edge("gt1", "result", "act", "event"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+
+    let catalog = build_core_catalog();
+    let diagnostics = crate::runtime::validate_all(&expanded, &catalog).unwrap_err();
+
+    assert_eq!(
+        diagnostics,
+        vec![
+            Diagnostic {
+                error: ValidationError::MissingPrimitive {
+                    id: "nope".to_string(),
+                    version: "0.1.0".to_string(),
+                },
+                node: Some("ghost".to_string()),
+                port: None,
+                edge: None,
+            },
+            Diagnostic {
+                error: ValidationError::InvalidEdgeKind {
+                    from: PrimitiveKind::Compute,
+                    to: PrimitiveKind::Action,
+                },
+                node: Some("act".to_string()),
+                port: Some("event".to_string()),
+                edge: Some(2),
+            },
+            Diagnostic {
+                error: ValidationError::MissingRequiredInput {
+                    node: "gt1".to_string(),
+                    input: "b".to_string(),
+                },
+                node: Some("gt1".to_string()),
+                port: Some("b".to_string()),
+                edge: None,
+            },
+            Diagnostic {
+                error: ValidationError::MissingInputMetadata {
+                    node: "gt1".to_string(),
+                    input: "typo".to_string(),
+                },
+                node: Some("gt1".to_string()),
+                port: Some("typo".to_string()),
+                edge: Some(1),
+            },
+            Diagnostic {
+                error: ValidationError::TypeMismatch {
+                    from: "gt1".to_string(),
+                    output: "result".to_string(),
+                    to: "act".to_string(),
+                    input: "event".to_string(),
+                    expected: ValueType::Event,
+                    got: ValueType::Bool,
+                },
+                node: Some("act".to_string()),
+                port: Some("event".to_string()),
+                edge: Some(2),
+            },
+            Diagnostic::node(ValidationError::ActionNotGated("act".to_string()), "act"),
+        ]
+    );
+
+    // Fail-fast validation reports the first of these.
+    assert_eq!(
+        crate::runtime::validate(&expanded, &catalog).unwrap_err(),
+        diagnostics[0].error
+    );
+}
+
+#[test]
+fn parameters_are_checked_against_manifest_declarations() {
+    let mut constant = impl_node("k", "const_number", "0.1.0");
+    constant.parameters.insert(
+        "scale".to_string(),
+        crate::cluster::ParameterValue::Number(2.0),
+    );
+    let mut src = impl_node("src", "number_source", "0.1.0");
+    src.parameters.insert(
+        "value".to_string(),
+        crate::cluster::ParameterValue::Bool(true),
+    );
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([("k".to_string(), constant), ("src".to_string(), src)]),
+        edges: Vec::new(),
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+
+    let catalog = build_core_catalog();
+    let diagnostics = crate::runtime::validate_all(&expanded, &catalog).unwrap_err();
+
+    assert_eq!(
+        diagnostics,
+        vec![
+            Diagnostic::node(
+                ValidationError::UnknownParameter {
+                    node: "k".to_string(),
+                    parameter: "scale".to_string(),
+                },
+                "k",
+            ),
+            Diagnostic::node(
+                ValidationError::MissingRequiredParameter {
+                    node: "k".to_string(),
+                    parameter: "value".to_string(),
+                },
+                "k",
+            ),
+            Diagnostic::node(
+                ValidationError::ParameterTypeMismatch {
+                    node: "src".to_string(),
+                    parameter: "value".to_string(),
+                    expected: crate::cluster::ParameterType::Number,
+                    got: crate::cluster::ParameterType::Bool,
+                },
+                "src",
+            ),
+        ]
+    );
+}
+
+#[test]
+fn parameter_outside_declared_bounds_is_rejected() {
+    let mut metadata = source_metadata();
+    metadata.parameters = vec![crate::cluster::ParameterSpec {
+        name: "lookback".to_string(),
+        ty: crate::cluster::ParameterType::Int,
+        default: Some(crate::cluster::ParameterValue::Int(20)),
+        required: false,
+        bounds: Some(crate::common::bounds::Bounds::parse("[1, )").unwrap()),
+        variants: Vec::new(),
+    }];
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("window".to_string(), "v1".to_string()), metadata);
+
+    let mut node = impl_node("w", "window", "v1");
+    let graph = |node: ExpandedNode| ExpandedGraph {
+        nodes: HashMap::from([("w".to_string(), node)]),
+        edges: Vec::new(),
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+    assert!(crate::runtime::validate(&graph(node.clone()), &catalog).is_ok());
+
+    node.parameters.insert(
+        "lookback".to_string(),
+        crate::cluster::ParameterValue::Int(-5),
+    );
+    assert_eq!(
+        crate::runtime::validate(&graph(node), &catalog).unwrap_err(),
+        ValidationError::ParameterOutOfBounds {
+            node: "w".to_string(),
+            parameter: "lookback".to_string(),
+            bounds: "[1, )".to_string(),
+        }
+    );
+}
+
+#[test]
+fn series_counts_must_be_integers() {
+    let catalog = build_core_catalog();
+    let graph = |window: crate::cluster::ParameterValue| {
+        let mut node = impl_node("mean", "rolling_mean", "0.1.0");
+        node.parameters.insert("window".to_string(), window);
+        ExpandedGraph {
+            nodes: HashMap::from([("mean".to_string(), node)]),
+            edges: Vec::new(),
+            boundary_inputs: Vec::new(),
+            boundary_outputs: Vec::new(),
+        }
+    };
+
+    let diagnostics = crate::runtime::validate_all(
+        &graph(crate::cluster::ParameterValue::Number(2.5)),
+        &catalog,
+    )
+    .unwrap_err();
+    assert_eq!(
+        diagnostics[0].error,
+        ValidationError::ParameterTypeMismatch {
+            node: "mean".to_string(),
+            parameter: "window".to_string(),
+            expected: crate::cluster::ParameterType::Int,
+            got: crate::cluster::ParameterType::Number,
+        }
+    );
+
+    // Only the unwired series input is left to report.
+    let diagnostics =
+        crate::runtime::validate_all(&graph(crate::cluster::ParameterValue::Int(2)), &catalog)
+            .unwrap_err();
+    assert!(
+        diagnostics
+            .iter()
+            .all(|d| matches!(d.error, ValidationError::MissingRequiredInput { .. })),
+        "{:?}",
+        diagnostics
+    );
+}
+
+#[test]
+fn catalog_holds_bounds_parsed_at_registration() {
+    use crate::catalog::{CatalogError, CorePrimitiveCatalog};
+    use crate::common::bounds::{Bounds, BoundsError};
+    use crate::common::parameter::ParameterDeclarationError;
+    use crate::compute::implementations::diff::diff_manifest;
+
+    let diff = build_core_catalog()
+        .get("diff", &"0.1.0".to_string())
+        .unwrap();
+    assert_eq!(
+        diff.parameters[0].bounds,
+        Some(Bounds::parse("[1, )").unwrap())
+    );
+
+    let mut manifest = diff_manifest();
+    manifest.parameters[0].bounds = Some("[1, ".to_string());
+    let error = CorePrimitiveCatalog::new()
+        .register_compute(manifest)
+        .unwrap_err();
+    assert!(
+        matches!(
+            &error,
+            CatalogError::InvalidParameterDeclaration {
+                primitive,
+                parameter,
+                error: ParameterDeclarationError::InvalidBounds(BoundsError::Syntax(_)),
+            } if primitive == "diff" && parameter == "periods"
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn enum_parameter_outside_declared_variants_is_rejected() {
+    let mut metadata = source_metadata();
+    metadata.parameters = vec![crate::cluster::ParameterSpec {
+        name: "side".to_string(),
+        ty: crate::cluster::ParameterType::Enum,
+        default: Some(crate::cluster::ParameterValue::Enum("buy".to_string())),
+        required: false,
+        bounds: None,
+        variants: vec!["buy".to_string(), "sell".to_string()],
+    }];
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("order".to_string(), "v1".to_string()), metadata);
+
+    let mut node = impl_node("o", "order", "v1");
+    node.parameters.insert(
+        "side".to_string(),
+        crate::cluster::ParameterValue::Enum("hold".to_string()),
+    );
+    let graph = ExpandedGraph {
+        nodes: HashMap::from([("o".to_string(), node)]),
+        edges: Vec::new(),
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+    assert_eq!(
+        crate::runtime::validate(&graph, &catalog).unwrap_err(),
+        ValidationError::UnknownEnumVariant {
+            node: "o".to_string(),
+            parameter: "side".to_string(),
+            variant: "hold".to_string(),
+        }
+    );
+}
+
+#[test]
+fn validation_fills_in_parameter_defaults() {
+    let mut expanded = gated_action_graph();
+    expanded.nodes.get_mut("act").unwrap().parameters.clear();
+    let catalog = build_core_catalog();
+    let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+
+    for node in validated.nodes.values() {
+        let declared = catalog
+            .get(&node.impl_id, &node.version)
+            .unwrap()
+            .parameters;
+        assert_eq!(node.parameters.len(), declared.len(), "{}", node.runtime_id);
+    }
+    assert_eq!(
+        validated.nodes["act"].parameters.get("accept"),
+        Some(&crate::cluster::ParameterValue::Bool(true))
+    );
+}
+
+#[test]
+fn cycle_diagnostic_lists_participating_nodes() {
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("add".to_string(), "v1".to_string()), add_metadata());
+    catalog
+        .metadata
+        .insert(("src".to_string(), "v1".to_string()), source_metadata());
+
+    // src -> a1 <-> a2 -> a3: a3 is downstream of the cycle but not part of it.
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            ("src".to_string(), impl_node("src", "src", "v1")),
+            ("a1".to_string(), impl_node("a1", "add", "v1")),
+            ("a2".to_string(), impl_node("a2", "add", "v1")),
+            ("a3".to_string(), impl_node("a3", "add", "v1")),
+        ]),
+        edges: vec![
+            port_edge("src", "out", "a1", "a"),
+            port_edge("a2", "result", "a1", "b"),
+            port_edge("a1", "result", "a2", "a"),
+            port_edge("src", "out", "a2", "b"),
+            port_edge("a2", "result", "a3", "a"),
+            port_edge("src", "out", "a3", "b"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+
+    let diagnostics = crate::runtime::validate_all(&expanded, &catalog).unwrap_err();
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic::node(
+            ValidationError::CycleDetected {
+                nodes: vec!["a1".to_string(), "a2".to_string()],
+            },
+            "a1",
+        )]
+    );
+}
+
+#[test]
+fn boundary_outputs_are_checked_and_typed_at_validation() {
+    let (catalog, _, _) = running_sum_setup();
+    let validated = crate::runtime::validate(&running_sum_graph("plain_add"), &catalog).unwrap();
+    assert_eq!(
+        validated.output_signature(),
+        vec![("total", &ValueType::Number)]
+    );
+
+    let mut typo = running_sum_graph("plain_add");
+    typo.boundary_outputs[0].maps_to.port_name = "reslt".to_string();
+    typo.boundary_outputs.push(crate::cluster::OutputPortSpec {
+        name: "ghost".to_string(),
+        maps_to: crate::cluster::OutputRef {
+            node_id: "nope".to_string(),
+            port_name: "result".to_string(),
+        },
+    });
+    let errors: Vec<ValidationError> = crate::runtime::validate_all(&typo, &catalog)
+        .unwrap_err()
+        .into_iter()
+        .map(|diagnostic| diagnostic.error)
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError::UnknownBoundaryPort {
+                output: "total".to_string(),
+                node: "sum".to_string(),
+                port: "reslt".to_string(),
+            },
+            ValidationError::UnknownBoundaryNode {
+                output: "ghost".to_string(),
+                node: "nope".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn select_binds_its_type_variable_per_node() {
+    let sourced = |id: &str, impl_id: &str, parameter: crate::cluster::ParameterValue| {
+        let mut node = impl_node(id, impl_id, "0.1.0");
+        node.parameters = HashMap::from([("value".to_string(), parameter)]);
+        (id.to_string(), node)
+    };
+    let output = |name: &str, node: &str| crate::cluster::OutputPortSpec {
+        name: name.to_string(),
+        maps_to: crate::cluster::OutputRef {
+            node_id: node.to_string(),
+            port_name: "result".to_string(),
+        },
+    };
+    let graph = |edges: Vec<crate::cluster::ExpandedEdge>| ExpandedGraph {
+        nodes: HashMap::from([
+            sourced(
+                "flag",
+                "boolean_source",
+                crate::cluster::ParameterValue::Bool(false),
+            ),
+            sourced(
+                "n",
+                "number_source",
+                crate::cluster::ParameterValue::Number(4.0),
+            ),
+            ("pick".to_string(), impl_node("pick", "select", "0.1.0")),
+            ("chain".to_string(), impl_node("chain", "select", "0.1.0")),
+        ]),
+        edges,
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![output("picked", "pick"), output("chained", "chain")],
+    };
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+
+    // Bool branches make `pick` a Bool select; a chained select takes T from it.
+    let bools = graph(vec![
+        port_edge("flag", "value", "pick", "cond"),
+        port_edge("flag", "value", "pick", "when_true"),
+        port_edge("flag", "value", "pick", "when_false"),
+        port_edge("flag", "value", "chain", "cond"),
+        port_edge("pick", "result", "chain", "when_true"),
+        port_edge("flag", "value", "chain", "when_false"),
+    ]);
+    let validated = crate::runtime::validate(&bools, &catalog).unwrap();
+    assert_eq!(
+        validated.output_signature(),
+        vec![("picked", &ValueType::Bool), ("chained", &ValueType::Bool)]
+    );
+    let report =
+        crate::runtime::execute(&validated, &registries, &ExecutionContext::default()).unwrap();
+    assert_eq!(report.outputs["chained"], RuntimeValue::Bool(false));
+
+    // The first edge into T binds it; a branch of another type is a mismatch.
+    let mixed = graph(vec![
+        port_edge("flag", "value", "pick", "cond"),
+        port_edge("n", "value", "pick", "when_true"),
+        port_edge("flag", "value", "pick", "when_false"),
+        port_edge("flag", "value", "chain", "cond"),
+        port_edge("pick", "result", "chain", "when_true"),
+        port_edge("n", "value", "chain", "when_false"),
+    ]);
+    let errors: Vec<ValidationError> = crate::runtime::validate_all(&mixed, &catalog)
+        .unwrap_err()
+        .into_iter()
+        .map(|diagnostic| diagnostic.error)
+        .collect();
+    assert_eq!(
+        errors,
+        vec![ValidationError::TypeMismatch {
+            from: "flag".to_string(),
+            output: "value".to_string(),
+            to: "pick".to_string(),
+            input: "when_false".to_string(),
+            expected: ValueType::Number,
+            got: ValueType::Bool,
+        }]
+    );
+}
+
+fn core_registries_ref(core: &crate::catalog::CoreRegistries) -> Registries<'_> {
+    Registries {
+        sources: &core.sources,
+        computes: &core.computes,
+        triggers: &core.triggers,
+        actions: &core.actions,
+    }
+}
+
+/// src_a=1.0, src_b=3.0 -> gt -> emit_if_true -> ack_action; the trigger does not emit.
+fn gated_action_graph() -> ExpandedGraph {
+    let mut src_a = impl_node("src_a", "number_source", "0.1.0");
+    src_a.parameters.insert(
+        "value".to_string(),
+        crate::cluster::ParameterValue::Number(1.0),
+    );
+    let mut src_b = impl_node("src_b", "number_source", "0.1.0");
+    src_b.parameters.insert(
+        "value".to_string(),
+        crate::cluster::ParameterValue::Number(3.0),
+    );
+    let mut act = impl_node("act", "ack_action", "0.1.0");
+    act.parameters.insert(
+        "accept".to_string(),
+        crate::cluster::ParameterValue::Bool(true),
+    );
+
+    ExpandedGraph {
+        nodes: HashMap::from([
+            ("src_a".to_string(), src_a),
+            ("src_b".to_string(), src_b),
+            ("gt1".to_string(), impl_node("gt1", "gt", "0.1.0")),
+            (
+                "emit".to_string(),
+                impl_node("emit", "emit_if_true", "0.1.0"),
+            ),
+            ("act".to_string(), act),
+        ]),
+        edges: vec![
+            port_edge("src_a", "value", "gt1", "a"),
+            port_edge("src_b", "value", "gt1", "b"),
+            port_edge("gt1", "result", "emit", "input"),
+            port_edge("emit", "event", "act", "event"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "action_outcome".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "act".to_string(),
+                port_name: "outcome".to_string(),
+            },
+        }],
+    }
+}
+
+#[test]
+fn trace_records_every_node_in_topo_order() {
+    let expanded = gated_action_graph();
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+
+    let untraced = run(
+        &expanded,
+        &catalog,
+        &registries,
+        &ExecutionContext::default(),
+    )
+    .unwrap();
+    assert!(untraced.trace.is_none());
+
+    let ctx = ExecutionContext {
+        trace: true,
+        ..ExecutionContext::default()
+    };
+    let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
+    let trace = report.trace.expect("trace requested");
+
+    let order: Vec<&str> = trace.iter().map(|t| t.node.as_str()).collect();
+    assert_eq!(order, vec!["src_a", "src_b", "gt1", "emit", "act"]);
+
+    let gt = &trace[2];
+    assert_eq!(gt.impl_id, "gt");
+    assert_eq!(gt.kind, PrimitiveKind::Compute);
+    assert_eq!(gt.inputs.get("a"), Some(&RuntimeValue::Number(1.0)));
+    assert_eq!(gt.inputs.get("b"), Some(&RuntimeValue::Number(3.0)));
+    assert_eq!(gt.outputs.get("result"), Some(&RuntimeValue::Bool(false)));
+    assert!(!gt.skipped);
+
+    let act = &trace[4];
+    assert_eq!(
+        act.parameters.get("accept"),
+        Some(&crate::cluster::ParameterValue::Bool(true))
+    );
+    assert_eq!(
+        act.inputs.get("event"),
+        Some(&RuntimeValue::Event(RuntimeEvent::Trigger(
+            crate::trigger::TriggerEvent::NotEmitted
+        )))
+    );
+    assert!(act.skipped);
+    assert_eq!(
+        act.outputs.get("outcome"),
+        Some(&RuntimeValue::Event(RuntimeEvent::Action(
+            action::ActionOutcome::Skipped
+        )))
+    );
+}
+
+struct PanickingSource {
+    manifest: SourcePrimitiveManifest,
+}
+
+impl SourcePrimitive for PanickingSource {
+    fn manifest(&self) -> &SourcePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn produce(
+        &self,
+        _parameters: &HashMap<String, crate::source::ParameterValue>,
+    ) -> HashMap<String, crate::common::Value> {
+        panic!("sensor offline")
+    }
+}
+
+#[test]
+fn primitive_panic_becomes_exec_error() {
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("boom".to_string(), "v1".to_string()), source_metadata());
+
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([("src".to_string(), impl_node("src", "boom", "v1"))]),
+        edges: Vec::new(),
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "out".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "src".to_string(),
+                port_name: "out".to_string(),
+            },
+        }],
+    };
+
+    let mut sources = SourceRegistry::new();
+    sources
+        .register(Box::new(PanickingSource {
+            manifest: ConstSource::new("boom", 0.0).manifest,
+        }))
+        .unwrap();
+    let computes = ComputeRegistry::new();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    let result = run(
+        &expanded,
+        &catalog,
+        &registries,
+        &ExecutionContext::default(),
+    );
+    match result {
+        Err(RuntimeError::Execution(ExecError::PrimitivePanicked {
+            node,
+            impl_id,
+            message,
+        })) => {
+            assert_eq!(node, "src");
+            assert_eq!(impl_id, "boom");
+            assert_eq!(message, "sensor offline");
+        }
+        other => panic!("Expected PrimitivePanicked, got {:?}", other),
+    }
+}
+
+/// Implements the fallible trait directly rather than going through the blanket impl.
+struct RejectingCompute {
+    manifest: crate::compute::ComputePrimitiveManifest,
+}
+
+impl crate::compute::FallibleComputePrimitive for RejectingCompute {
+    fn manifest(&self) -> &crate::compute::ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_compute(
+        &self,
+        inputs: &HashMap<String, crate::common::Value>,
+        _parameters: &HashMap<String, crate::common::Value>,
+        _state: Option<&mut crate::compute::PrimitiveState>,
+    ) -> Result<HashMap<String, crate::common::Value>, crate::compute::ComputeError> {
+        match inputs.get("b") {
+            Some(crate::common::Value::Number(b)) if *b == 0.0 => {
+                Err(crate::compute::ComputeError::InvalidInput {
+                    name: "b".to_string(),
+                    reason: "must be non-zero".to_string(),
+                })
+            }
+            _ => Ok(HashMap::from([(
+                "result".to_string(),
+                crate::common::Value::Number(1.0),
+            )])),
+        }
+    }
+}
+
+#[test]
+fn primitive_error_is_carried_through_exec_error() {
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("reject".to_string(), "v1".to_string()), add_metadata());
+    catalog
+        .metadata
+        .insert(("zero".to_string(), "v1".to_string()), source_metadata());
+
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            ("zero".to_string(), impl_node("zero", "zero", "v1")),
+            ("div".to_string(), impl_node("div", "reject", "v1")),
+        ]),
+        edges: vec![
+            port_edge("zero", "out", "div", "a"),
+            port_edge("zero", "out", "div", "b"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "result".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "div".to_string(),
+                port_name: "result".to_string(),
+            },
+        }],
+    };
+
+    let mut sources = SourceRegistry::new();
+    sources
+        .register(Box::new(ConstSource::new("zero", 0.0)))
+        .unwrap();
+    let mut manifest = crate::compute::implementations::add::add_manifest();
+    manifest.id = "reject".to_string();
+    manifest.version = "v1".to_string();
+    let mut computes = ComputeRegistry::new();
+    computes
+        .register(Box::new(RejectingCompute { manifest }))
+        .unwrap();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    let result = run(
+        &expanded,
+        &catalog,
+        &registries,
+        &ExecutionContext::default(),
+    );
+    match result {
+        Err(RuntimeError::Execution(ExecError::ComputeFailed { node, error })) => {
+            assert_eq!(node, "div");
+            assert_eq!(
+                error,
+                crate::compute::ComputeError::InvalidInput {
+                    name: "b".to_string(),
+                    reason: "must be non-zero".to_string(),
+                }
+            );
+        }
+        other => panic!("Expected ComputeFailed, got {:?}", other),
+    }
+}
+
+#[test]
+fn execution_requires_the_exact_pinned_version() {
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("add".to_string(), "0.2.0".to_string()), add_metadata());
+    catalog
+        .metadata
+        .insert(("one".to_string(), "v1".to_string()), source_metadata());
+
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            ("one".to_string(), impl_node("one", "one", "v1")),
+            ("sum".to_string(), impl_node("sum", "add", "0.2.0")),
+        ]),
+        edges: vec![
+            port_edge("one", "out", "sum", "a"),
+            port_edge("one", "out", "sum", "b"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+
+    let mut sources = SourceRegistry::new();
+    sources
+        .register(Box::new(ConstSource::new("one", 1.0)))
+        .unwrap();
+    // Only add@0.1.0 is registered.
+    let mut computes = ComputeRegistry::new();
+    computes.register(Box::new(Add::new())).unwrap();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    let result = run(
+        &expanded,
+        &catalog,
+        &registries,
+        &ExecutionContext::default(),
+    );
+    match result {
+        Err(RuntimeError::Execution(ExecError::UnknownPrimitive { id, version })) => {
+            assert_eq!(id, "add");
+            assert_eq!(version, "0.2.0");
+        }
+        other => panic!("Expected UnknownPrimitive, got {:?}", other),
+    }
+}
+
+/// Stateful test compute: accumulates `a + b` across passes.
+struct RunningSum {
+    manifest: crate::compute::ComputePrimitiveManifest,
+}
+
+impl crate::compute::ComputePrimitive for RunningSum {
+    fn manifest(&self) -> &crate::compute::ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn compute(
+        &self,
+        inputs: &HashMap<String, crate::common::Value>,
+        _parameters: &HashMap<String, crate::common::Value>,
+        state: Option<&mut crate::compute::PrimitiveState>,
+    ) -> HashMap<String, crate::common::Value> {
+        let state = state.expect("running_sum declares state");
+        let a = inputs.get("a").and_then(|v| v.as_number()).unwrap_or(0.0);
+        let b = inputs.get("b").and_then(|v| v.as_number()).unwrap_or(0.0);
+        let total = state
+            .data
+            .get("total")
+            .and_then(|v| v.as_number())
+            .unwrap_or(0.0)
+            + a
+            + b;
+        state
+            .data
+            .insert("total".to_string(), crate::common::Value::Number(total));
+        HashMap::from([("result".to_string(), crate::common::Value::Number(total))])
+    }
+}
+
+fn running_sum_graph(impl_id: &str) -> ExpandedGraph {
+    ExpandedGraph {
+        nodes: HashMap::from([
+            ("one".to_string(), impl_node("one", "one", "v1")),
+            ("sum".to_string(), impl_node("sum", impl_id, "v1")),
+        ]),
+        edges: vec![
+            port_edge("one", "out", "sum", "a"),
+            port_edge("one", "out", "sum", "b"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "total".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "sum".to_string(),
+                port_name: "result".to_string(),
+            },
+        }],
+    }
+}
+
+fn running_sum_setup() -> (TestCatalog, SourceRegistry, ComputeRegistry) {
+    let mut catalog = TestCatalog::default();
+    for id in ["running_sum", "plain_add"] {
+        catalog
+            .metadata
+            .insert((id.to_string(), "v1".to_string()), add_metadata());
+    }
+    catalog
+        .metadata
+        .insert(("one".to_string(), "v1".to_string()), source_metadata());
+
+    let mut sources = SourceRegistry::new();
+    sources
+        .register(Box::new(ConstSource::new("one", 1.0)))
+        .unwrap();
+
+    let mut computes = ComputeRegistry::new();
+    let mut stateful = crate::compute::implementations::add::add_manifest();
+    stateful.id = "running_sum".to_string();
+    stateful.version = "v1".to_string();
+    stateful.state.stateful = true;
+    computes
+        .register(Box::new(RunningSum { manifest: stateful }))
+        .unwrap();
+    let mut plain = crate::compute::implementations::add::add_manifest();
+    plain.id = "plain_add".to_string();
+    plain.version = "v1".to_string();
+    computes
+        .register(Box::new(RunningSum { manifest: plain }))
+        .unwrap();
+
+    (catalog, sources, computes)
+}
+
+#[test]
+fn stateful_compute_state_carries_across_passes() {
+    let (catalog, sources, computes) = running_sum_setup();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+    let expanded = running_sum_graph("running_sum");
+
+    let mut ctx = ExecutionContext::default();
+    for expected in [2.0, 4.0, 6.0] {
+        let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
+        assert_eq!(
+            report.outputs.get("total"),
+            Some(&RuntimeValue::Number(expected))
+        );
+        assert_eq!(
+            report.compute_state["sum"].data.get("total"),
+            Some(&crate::common::Value::Number(expected))
+        );
+        ctx.compute_state = report.compute_state;
+    }
+}
+
+#[test]
+fn stateless_compute_never_receives_state() {
+    let (catalog, sources, computes) = running_sum_setup();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    // Same implementation, but its manifest does not declare state: it must see None.
+    let expanded = running_sum_graph("plain_add");
+    let result = run(
+        &expanded,
+        &catalog,
+        &registries,
+        &ExecutionContext::default(),
+    );
+    assert!(matches!(
+        result,
+        Err(RuntimeError::Execution(ExecError::PrimitivePanicked { ref node, .. })) if node == "sum"
+    ));
+
+    // State addressed to a node that does not declare it is rejected up front.
+    let ctx = ExecutionContext {
+        compute_state: HashMap::from([(
+            "sum".to_string(),
+            crate::compute::PrimitiveState::default(),
+        )]),
+        ..ExecutionContext::default()
+    };
+    let result = run(&expanded, &catalog, &registries, &ctx);
+    match result {
+        Err(RuntimeError::Execution(ExecError::UndeclaredState { node })) => {
+            assert_eq!(node, "sum");
+        }
+        other => panic!("Expected UndeclaredState, got {:?}", other),
+    }
+}
+
+/// Source whose value the test can change between passes.
+struct SharedSource {
+    manifest: SourcePrimitiveManifest,
+    value: Rc<Cell<f64>>,
+}
+
+impl SourcePrimitive for SharedSource {
+    fn manifest(&self) -> &SourcePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn produce(
+        &self,
+        _parameters: &HashMap<String, crate::source::ParameterValue>,
+    ) -> HashMap<String, crate::common::Value> {
+        HashMap::from([(
+            "out".to_string(),
+            crate::common::Value::Number(self.value.get()),
+        )])
+    }
+}
+
+/// Counts how often the wrapped primitive is evaluated.
+struct Counted<P> {
+    inner: P,
+    calls: Arc<AtomicUsize>,
+}
+
+impl<P: crate::compute::ComputePrimitive> crate::compute::ComputePrimitive for Counted<P> {
+    fn manifest(&self) -> &crate::compute::ComputePrimitiveManifest {
+        self.inner.manifest()
+    }
+
+    fn compute(
+        &self,
+        inputs: &HashMap<String, crate::common::Value>,
+        parameters: &HashMap<String, crate::common::Value>,
+        state: Option<&mut crate::compute::PrimitiveState>,
+    ) -> HashMap<String, crate::common::Value> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.compute(inputs, parameters, state)
+    }
+}
+
+impl<P: action::ActionPrimitive> action::ActionPrimitive for Counted<P> {
+    fn manifest(&self) -> &action::ActionPrimitiveManifest {
+        self.inner.manifest()
+    }
+
+    fn execute(
+        &self,
+        inputs: &HashMap<String, action::ActionValue>,
+        parameters: &HashMap<String, action::ParameterValue>,
+    ) -> HashMap<String, action::ActionValue> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.execute(inputs, parameters)
+    }
+}
+
+fn calls(counter: &AtomicUsize) -> usize {
+    counter.load(Ordering::SeqCst)
+}
+
+#[test]
+fn incremental_pass_reevaluates_only_the_changed_cone() {
+    let core_catalog = build_core_catalog();
+    let mut catalog = TestCatalog::default();
+    for id in ["x", "y"] {
+        catalog
+            .metadata
+            .insert((id.to_string(), "v1".to_string()), source_metadata());
+    }
+    for id in ["add", "gt", "emit_if_true", "ack_action"] {
+        let key = (id.to_string(), "0.1.0".to_string());
+        let meta = core_catalog.get(id, &key.1).unwrap();
+        catalog.metadata.insert(key, meta);
+    }
+
+    // x -> sum_x, y -> sum_y, (sum_x > sum_y) -> emit -> act
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            ("x".to_string(), impl_node("x", "x", "v1")),
+            ("y".to_string(), impl_node("y", "y", "v1")),
+            ("sum_x".to_string(), impl_node("sum_x", "add", "0.1.0")),
+            ("sum_y".to_string(), impl_node("sum_y", "add", "0.1.0")),
+            ("gt1".to_string(), impl_node("gt1", "gt", "0.1.0")),
+            (
+                "emit".to_string(),
+                impl_node("emit", "emit_if_true", "0.1.0"),
+            ),
+            ("act".to_string(), impl_node("act", "ack_action", "0.1.0")),
+        ]),
+        edges: vec![
+            port_edge("x", "out", "sum_x", "a"),
+            port_edge("x", "out", "sum_x", "b"),
+            port_edge("y", "out", "sum_y", "a"),
+            port_edge("y", "out", "sum_y", "b"),
+            port_edge("sum_x", "result", "gt1", "a"),
+            port_edge("sum_y", "result", "gt1", "b"),
+            port_edge("gt1", "result", "emit", "input"),
+            port_edge("emit", "event", "act", "event"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "outcome".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "act".to_string(),
+                port_name: "outcome".to_string(),
+            },
+        }],
+    };
+
+    let x = Rc::new(Cell::new(2.0));
+    let y = Rc::new(Cell::new(1.0));
+    let mut sources = SourceRegistry::new();
+    for (id, value) in [("x", &x), ("y", &y)] {
+        sources
+            .register(Box::new(SharedSource {
+                manifest: ConstSource::new(id, 0.0).manifest,
+                value: value.clone(),
+            }))
+            .unwrap();
+    }
+
+    // sum_x and sum_y share one registered `add`, so `adds` counts both nodes.
+    let adds = Arc::new(AtomicUsize::new(0));
+    let gts = Arc::new(AtomicUsize::new(0));
+    let acts = Arc::new(AtomicUsize::new(0));
+    let mut computes = ComputeRegistry::new();
+    computes
+        .register(Box::new(Counted {
+            inner: Add::new(),
+            calls: adds.clone(),
+        }))
+        .unwrap();
+    computes
+        .register(Box::new(Counted {
+            inner: crate::compute::Gt::new(),
+            calls: gts.clone(),
+        }))
+        .unwrap();
+    let mut triggers = TriggerRegistry::new();
+    triggers
+        .register(Box::new(crate::trigger::EmitIfTrue::new()))
+        .unwrap();
+    let mut actions = action::ActionRegistry::new();
+    actions
+        .register(Box::new(Counted {
+            inner: action::AckAction::new(),
+            calls: acts.clone(),
+        }))
+        .unwrap();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+    let mut executor = IncrementalExecutor::new(validated.clone());
+    let ctx = ExecutionContext {
+        trace: true,
+        ..ExecutionContext::default()
+    };
+    let filled = RuntimeValue::Event(RuntimeEvent::Action(action::ActionOutcome::Filled));
+
+    // First pass evaluates everything.
+    let report = executor.execute(&registries, &ctx).unwrap();
+    assert_eq!(report.outputs.get("outcome"), Some(&filled));
+    assert_eq!((calls(&adds), calls(&gts), calls(&acts)), (2, 1, 1));
+
+    // Nothing changed: computes are reused, the action still runs once.
+    executor.execute(&registries, &ctx).unwrap();
+    assert_eq!((calls(&adds), calls(&gts), calls(&acts)), (2, 1, 2));
+
+    // Only x changed: sum_x and gt1 re-run, sum_y is reused.
+    x.set(3.0);
+    let report = executor.execute(&registries, &ctx).unwrap();
+    assert_eq!((calls(&adds), calls(&gts), calls(&acts)), (3, 2, 3));
+
+    // The incremental report matches a full pass.
+    let full = crate::runtime::execute(&validated, &registries, &ctx).unwrap();
+    assert_eq!(report.outputs, full.outputs);
+    assert_eq!(report.trace, full.trace);
+
+    // After a reset every node is evaluated again.
+    executor.reset();
+    let (adds_before, gts_before) = (calls(&adds), calls(&gts));
+    executor.execute(&registries, &ctx).unwrap();
+    assert_eq!(calls(&adds) - adds_before, 2);
+    assert_eq!(calls(&gts) - gts_before, 1);
+}
+
+/// src -> 32 independent adds -> pairwise adds -> gt against a threshold -> emit -> act
+fn fan_out_graph() -> ExpandedGraph {
+    let mut src = impl_node("src", "number_source", "0.1.0");
+    src.parameters.insert(
+        "value".to_string(),
+        crate::cluster::ParameterValue::Number(2.0),
+    );
+    let mut threshold = impl_node("threshold", "number_source", "0.1.0");
+    threshold.parameters.insert(
+        "value".to_string(),
+        crate::cluster::ParameterValue::Number(1.0),
+    );
+
+    let mut nodes = HashMap::from([
+        ("src".to_string(), src),
+        ("threshold".to_string(), threshold),
+        ("gt1".to_string(), impl_node("gt1", "gt", "0.1.0")),
+        (
+            "emit".to_string(),
+            impl_node("emit", "emit_if_true", "0.1.0"),
+        ),
+        ("act".to_string(), impl_node("act", "ack_action", "0.1.0")),
+    ]);
+    let mut edges = Vec::new();
+    let mut boundary_outputs = Vec::new();
+    for i in 0..32 {
+        let id = format!("add_{i}");
+        nodes.insert(id.clone(), impl_node(&id, "add", "0.1.0"));
+        edges.push(port_edge("src", "value", &id, "a"));
+        edges.push(port_edge("src", "value", &id, "b"));
+    }
+    for i in 0..16 {
+        let id = format!("pair_{i}");
+        nodes.insert(id.clone(), impl_node(&id, "add", "0.1.0"));
+        edges.push(port_edge(&format!("add_{}", 2 * i), "result", &id, "a"));
+        edges.push(port_edge(&format!("add_{}", 2 * i + 1), "result", &id, "b"));
+        boundary_outputs.push(crate::cluster::OutputPortSpec {
+            name: id.clone(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: id,
+                port_name: "result".to_string(),
+            },
+        });
+    }
+    edges.push(port_edge("pair_0", "result", "gt1", "a"));
+    edges.push(port_edge("threshold", "value", "gt1", "b"));
+    edges.push(port_edge("gt1", "result", "emit", "input"));
+    edges.push(port_edge("emit", "event", "act", "event"));
+    boundary_outputs.push(crate::cluster::OutputPortSpec {
+        name: "outcome".to_string(),
+        maps_to: crate::cluster::OutputRef {
+            node_id: "act".to_string(),
+            port_name: "outcome".to_string(),
+        },
+    });
+
+    ExpandedGraph {
+        nodes,
+        edges,
+        boundary_inputs: Vec::new(),
+        boundary_outputs,
+    }
+}
+
+#[test]
+fn parallel_execution_matches_sequential() {
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+    let ctx = ExecutionContext {
+        trace: true,
+        ..ExecutionContext::default()
+    };
+
+    for expanded in [fan_out_graph(), gated_action_graph()] {
+        let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+        let sequential = crate::runtime::execute(&validated, &registries, &ctx).unwrap();
+        for workers in [1, 4] {
+            let executor = ParallelExecutor::with_workers(validated.clone(), workers).unwrap();
+            let report = executor.execute(&registries, &ctx).unwrap();
+            assert_eq!(report.outputs, sequential.outputs);
+            assert_eq!(report.trace, sequential.trace);
+        }
+    }
+
+    let validated = crate::runtime::validate(&fan_out_graph(), &catalog).unwrap();
+    let executor = ParallelExecutor::with_workers(validated, 4).unwrap();
+    let widths: Vec<usize> = executor.levels().iter().map(Vec::len).collect();
+    assert_eq!(widths, vec![2, 32, 16, 1, 1, 1]);
+}
+
+#[test]
+fn parallel_execution_reports_the_sequential_error() {
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("zero".to_string(), "v1".to_string()), source_metadata());
+    catalog
+        .metadata
+        .insert(("reject".to_string(), "v1".to_string()), add_metadata());
+
+    // Four rejecting nodes in one level; the sequential pass stops at the first in topo order.
+    let mut nodes = HashMap::from([("zero".to_string(), impl_node("zero", "zero", "v1"))]);
+    let mut edges = Vec::new();
+    for i in 0..4 {
+        let id = format!("div_{i}");
+        nodes.insert(id.clone(), impl_node(&id, "reject", "v1"));
+        edges.push(port_edge("zero", "out", &id, "a"));
+        edges.push(port_edge("zero", "out", &id, "b"));
+    }
+    let expanded = ExpandedGraph {
+        nodes,
+        edges,
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+
+    let mut sources = SourceRegistry::new();
+    sources
+        .register(Box::new(ConstSource::new("zero", 0.0)))
+        .unwrap();
+    let mut manifest = crate::compute::implementations::add::add_manifest();
+    manifest.id = "reject".to_string();
+    manifest.version = "v1".to_string();
+    let mut computes = ComputeRegistry::new();
+    computes
+        .register(Box::new(RejectingCompute { manifest }))
+        .unwrap();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+    let ctx = ExecutionContext::default();
+    let sequential = crate::runtime::execute(&validated, &registries, &ctx).unwrap_err();
+    let parallel = ParallelExecutor::with_workers(validated, 4)
+        .unwrap()
+        .execute(&registries, &ctx)
+        .unwrap_err();
+    assert_eq!(format!("{parallel:?}"), format!("{sequential:?}"));
+}
+
+/// Records the name of every thread it is evaluated on.
+struct ThreadRecordingCompute {
+    manifest: crate::compute::ComputePrimitiveManifest,
+    threads: Arc<std::sync::Mutex<std::collections::BTreeSet<String>>>,
+}
+
+impl crate::compute::ComputePrimitive for ThreadRecordingCompute {
+    fn manifest(&self) -> &crate::compute::ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn compute(
+        &self,
+        _inputs: &HashMap<String, crate::common::Value>,
+        _parameters: &HashMap<String, crate::common::Value>,
+        _state: Option<&mut crate::compute::PrimitiveState>,
+    ) -> HashMap<String, crate::common::Value> {
+        let name = std::thread::current()
+            .name()
+            .unwrap_or_default()
+            .to_string();
+        self.threads.lock().unwrap().insert(name);
+        HashMap::from([("result".to_string(), crate::common::Value::Number(1.0))])
+    }
+}
+
+#[test]
+fn parallel_executor_reuses_its_worker_pool() {
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("zero".to_string(), "v1".to_string()), source_metadata());
+    catalog
+        .metadata
+        .insert(("record".to_string(), "v1".to_string()), add_metadata());
+
+    let mut nodes = HashMap::from([("zero".to_string(), impl_node("zero", "zero", "v1"))]);
+    let mut edges = Vec::new();
+    for i in 0..8 {
+        let id = format!("record_{i}");
+        nodes.insert(id.clone(), impl_node(&id, "record", "v1"));
+        edges.push(port_edge("zero", "out", &id, "a"));
+        edges.push(port_edge("zero", "out", &id, "b"));
+    }
+    let expanded = ExpandedGraph {
+        nodes,
+        edges,
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+
+    let mut sources = SourceRegistry::new();
+    sources
+        .register(Box::new(ConstSource::new("zero", 0.0)))
+        .unwrap();
+    let mut manifest = crate::compute::implementations::add::add_manifest();
+    manifest.id = "record".to_string();
+    manifest.version = "v1".to_string();
+    let threads = Arc::new(std::sync::Mutex::new(std::collections::BTreeSet::new()));
+    let mut computes = ComputeRegistry::new();
+    computes
+        .register(Box::new(ThreadRecordingCompute {
+            manifest,
+            threads: threads.clone(),
+        }))
+        .unwrap();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+    let executor = ParallelExecutor::with_workers(validated, 2).unwrap();
+    assert_eq!(executor.workers(), 2);
+    let ctx = ExecutionContext::default();
+    for _ in 0..3 {
+        executor.execute(&registries, &ctx).unwrap();
+    }
+
+    // Every pass runs on the same two named workers rather than fresh threads.
+    let threads = threads.lock().unwrap();
+    assert!(!threads.is_empty() && threads.len() <= 2, "{threads:?}");
+    assert!(threads.iter().all(|name| name.starts_with("ergo-compute-")));
+}
+
+#[test]
+fn compiled_plan_interns_ports_and_runs_repeatedly() {
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+    let validated = crate::runtime::validate(&fan_out_graph(), &catalog).unwrap();
+
+    let plan = ExecutionPlan::compile(&validated);
+    assert_eq!(plan.node_count(), validated.nodes.len());
+    // One slot per declared output port: every node here declares exactly one.
+    assert_eq!(plan.slot_count(), validated.nodes.len());
+
+    let ctx = ExecutionContext {
+        trace: true,
+        ..ExecutionContext::default()
+    };
+    let expected = crate::runtime::execute(&validated, &registries, &ctx).unwrap();
+    for _ in 0..3 {
+        let report = execute_plan(&plan, &registries, &ctx).unwrap();
+        assert_eq!(report.outputs, expected.outputs);
+        assert_eq!(report.trace, expected.trace);
+    }
+    assert_eq!(
+        expected.outputs.get("pair_0"),
+        Some(&RuntimeValue::Number(8.0))
+    );
+}
+
+#[test]
+fn second_edge_into_single_input_is_rejected() {
+    let (catalog, _, _) = running_sum_setup();
+    let mut expanded = running_sum_graph("plain_add");
+    expanded.edges.push(port_edge("one", "out", "sum", "a"));
+
+    let diagnostics = crate::runtime::validate_all(&expanded, &catalog).unwrap_err();
+
+    assert_eq!(
+        diagnostics,
+        vec![Diagnostic {
+            error: ValidationError::MultipleEdgesIntoSingleInput {
+                node: "sum".to_string(),
+                input: "a".to_string(),
+            },
+            node: Some("sum".to_string()),
+            port: Some("a".to_string()),
+            edge: Some(2),
+        }]
+    );
+}
+
+/// Variadic test compute: reads its `Multiple` input as digits, most significant first.
+struct Digits {
+    manifest: crate::compute::ComputePrimitiveManifest,
+}
+
+impl crate::compute::ComputePrimitive for Digits {
+    fn manifest(&self) -> &crate::compute::ComputePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn compute(
+        &self,
+        inputs: &HashMap<String, crate::common::Value>,
+        _parameters: &HashMap<String, crate::common::Value>,
+        _state: Option<&mut crate::compute::PrimitiveState>,
+    ) -> HashMap<String, crate::common::Value> {
+        let digits = inputs["digits"]
+            .as_list()
+            .expect("multiple input is a list");
+        let value = digits
+            .iter()
+            .map(|d| d.as_number().expect("number digit"))
+            .fold(0.0, |acc, d| acc * 10.0 + d);
+        HashMap::from([("result".to_string(), crate::common::Value::Number(value))])
+    }
+}
+
+#[test]
+fn multiple_input_receives_values_in_edge_order() {
+    let mut metadata = add_metadata();
+    metadata.inputs = vec![InputMetadata {
+        name: "digits".to_string(),
+        value_type: ValueType::Number,
+        required: true,
+        cardinality: crate::cluster::Cardinality::Multiple,
+    }];
+    let mut catalog = TestCatalog::default();
+    catalog
+        .metadata
+        .insert(("digits".to_string(), "v1".to_string()), metadata);
+    for id in ["one", "two"] {
+        catalog
+            .metadata
+            .insert((id.to_string(), "v1".to_string()), source_metadata());
+    }
+
+    let mut sources = SourceRegistry::new();
+    sources
+        .register(Box::new(ConstSource::new("one", 1.0)))
+        .unwrap();
+    sources
+        .register(Box::new(ConstSource::new("two", 2.0)))
+        .unwrap();
+    let mut manifest = crate::compute::implementations::add::add_manifest();
+    manifest.id = "digits".to_string();
+    manifest.version = "v1".to_string();
+    manifest.inputs = vec![crate::compute::InputSpec {
+        name: "digits".to_string(),
+        value_type: crate::common::ValueType::Number,
+        required: true,
+        cardinality: crate::compute::Cardinality::Multiple,
+    }];
+    let mut computes = ComputeRegistry::new();
+    computes.register(Box::new(Digits { manifest })).unwrap();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            ("one".to_string(), impl_node("one", "one", "v1")),
+            ("two".to_string(), impl_node("two", "two", "v1")),
+            ("join".to_string(), impl_node("join", "digits", "v1")),
+        ]),
+        edges: vec![
+            port_edge("two", "out", "join", "digits"),
+            port_edge("one", "out", "join", "digits"),
+            port_edge("two", "out", "join", "digits"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "joined".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "join".to_string(),
+                port_name: "result".to_string(),
+            },
+        }],
+    };
+
+    let ctx = ExecutionContext {
+        trace: true,
+        ..ExecutionContext::default()
+    };
+    let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
+    assert_eq!(
+        report.outputs.get("joined"),
+        Some(&RuntimeValue::Number(212.0))
+    );
+    let join = report
+        .trace
+        .unwrap()
+        .into_iter()
+        .find(|t| t.node == "join")
+        .unwrap();
+    assert_eq!(
+        join.inputs.get("digits"),
+        Some(&RuntimeValue::List(vec![
+            RuntimeValue::Number(2.0),
+            RuntimeValue::Number(1.0),
+            RuntimeValue::Number(2.0),
+        ]))
+    );
+}
+
+/// const 1.0 / const 0.0 -> gt(quotient, 0.0): the division is infinite.
+fn division_by_zero_graph() -> ExpandedGraph {
+    let constant = |id: &str, value: f64| {
+        let mut node = impl_node(id, "const_number", "0.1.0");
+        node.parameters = HashMap::from([(
+            "value".to_string(),
+            crate::cluster::ParameterValue::Number(value),
+        )]);
+        (id.to_string(), node)
+    };
+    ExpandedGraph {
+        nodes: HashMap::from([
+            constant("one", 1.0),
+            constant("zero", 0.0),
+            ("div".to_string(), impl_node("div", "divide", "0.1.0")),
+            ("positive".to_string(), impl_node("positive", "gt", "0.1.0")),
+        ]),
+        edges: vec![
+            port_edge("one", "value", "div", "a"),
+            port_edge("zero", "value", "div", "b"),
+            port_edge("div", "result", "positive", "a"),
+            port_edge("zero", "value", "positive", "b"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![
+            crate::cluster::OutputPortSpec {
+                name: "quotient".to_string(),
+                maps_to: crate::cluster::OutputRef {
+                    node_id: "div".to_string(),
+                    port_name: "result".to_string(),
+                },
+            },
+            crate::cluster::OutputPortSpec {
+                name: "positive".to_string(),
+                maps_to: crate::cluster::OutputRef {
+                    node_id: "positive".to_string(),
+                    port_name: "result".to_string(),
+                },
+            },
+        ],
+    }
+}
+
+#[test]
+fn non_finite_policy_governs_compute_outputs() {
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+    let expanded = division_by_zero_graph();
+    let with_policy = |non_finite| ExecutionContext {
+        non_finite,
+        ..ExecutionContext::default()
+    };
+
+    let report = run(
+        &expanded,
+        &catalog,
+        &registries,
+        &with_policy(NonFinitePolicy::Propagate),
+    )
+    .unwrap();
+    assert_eq!(
+        report.outputs["quotient"],
+        RuntimeValue::Number(f64::INFINITY)
+    );
+    assert_eq!(report.outputs["positive"], RuntimeValue::Bool(true));
+
+    let report = run(
+        &expanded,
+        &catalog,
+        &registries,
+        &with_policy(NonFinitePolicy::substitute(-1.0).unwrap()),
+    )
+    .unwrap();
+    assert_eq!(report.outputs["quotient"], RuntimeValue::Number(-1.0));
+    assert_eq!(report.outputs["positive"], RuntimeValue::Bool(false));
+
+    let names_division = |error: &ExecError| matches!(error, ExecError::NonFiniteValue { node, port } if node == "div" && port == "result");
+    let ctx = with_policy(NonFinitePolicy::Error);
+    match run(&expanded, &catalog, &registries, &ctx) {
+        Err(RuntimeError::Execution(e)) => assert!(names_division(&e), "{:?}", e),
+        other => panic!("Expected NonFiniteValue, got {:?}", other),
+    }
+    let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+    let parallel = ParallelExecutor::with_workers(validated.clone(), 2).unwrap();
+    assert!(names_division(
+        &parallel.execute(&registries, &ctx).unwrap_err()
+    ));
+
+    // A policy change invalidates outputs reused from the previous pass.
+    let mut incremental = IncrementalExecutor::new(validated);
+    incremental
+        .execute(&registries, &with_policy(NonFinitePolicy::Propagate))
+        .unwrap();
+    assert!(names_division(
+        &incremental.execute(&registries, &ctx).unwrap_err()
+    ));
+}
+
+#[test]
+fn non_finite_substitute_is_rejected() {
+    assert!(NonFinitePolicy::substitute(f64::NAN).is_none());
+    assert!(NonFinitePolicy::substitute(f64::INFINITY).is_none());
+    assert!(NonFinitePolicy::substitute(f64::NEG_INFINITY).is_none());
+    assert!(matches!(
+        NonFinitePolicy::substitute(-1.5),
+        Some(NonFinitePolicy::Substitute(n)) if n.get() == -1.5
+    ));
+}
+
+#[test]
+fn string_values_flow_from_sources_through_computes() {
+    let parameterised = |id: &str, impl_id: &str, name: &str, value| {
+        let mut node = impl_node(id, impl_id, "0.1.0");
+        node.parameters = HashMap::from([(name.to_string(), value)]);
+        (id.to_string(), node)
+    };
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            parameterised(
+                "symbol",
+                "string_source",
+                "value",
+                crate::cluster::ParameterValue::String("EURUSD".to_string()),
+            ),
+            parameterised(
+                "price",
+                "number_source",
+                "value",
+                crate::cluster::ParameterValue::Number(1.08456),
+            ),
+            parameterised(
+                "price_text",
+                "to_string",
+                "precision",
+                crate::cluster::ParameterValue::Int(2),
+            ),
+            parameterised(
+                "label",
+                "format",
+                "template",
+                crate::cluster::ParameterValue::String("{a} @ {b}".to_string()),
+            ),
+        ]),
+        edges: vec![
+            port_edge("price", "value", "price_text", "value"),
+            port_edge("symbol", "value", "label", "a"),
+            port_edge("price_text", "result", "label", "b"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "label".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "label".to_string(),
+                port_name: "result".to_string(),
+            },
+        }],
+    };
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+
+    let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+    assert_eq!(
+        validated.output_signature(),
+        vec![("label", &ValueType::String)]
+    );
+    let report =
+        crate::runtime::execute(&validated, &registries, &ExecutionContext::default()).unwrap();
+    assert_eq!(
+        report.outputs["label"],
+        RuntimeValue::String("EURUSD @ 1.08".to_string())
+    );
+}
+
+#[test]
+fn format_templates_are_checked_at_validation() {
+    let catalog = build_core_catalog();
+    let graph = |template: &str| {
+        let mut node = impl_node("label", "format", "0.1.0");
+        node.parameters.insert(
+            "template".to_string(),
+            crate::cluster::ParameterValue::String(template.to_string()),
+        );
+        ExpandedGraph {
+            nodes: HashMap::from([("label".to_string(), node)]),
+            edges: Vec::new(),
+            boundary_inputs: Vec::new(),
+            boundary_outputs: Vec::new(),
+        }
+    };
+
+    for template in ["{a} @ {b}", "{{literal}}", "plain"] {
+        assert!(
+            crate::runtime::validate(&graph(template), &catalog).is_ok(),
+            "{template}"
+        );
+    }
+    for template in ["{e}", "{a", "a}", "{}"] {
+        assert!(
+            matches!(
+                crate::runtime::validate(&graph(template), &catalog),
+                Err(ValidationError::ParameterOutOfBounds { ref parameter, .. })
+                    if parameter == "template"
+            ),
+            "{template}"
+        );
+    }
+}
+
+/// Outputs how many environment entries the runtime let it see.
+struct EnvironmentPeekSource {
+    manifest: SourcePrimitiveManifest,
+}
+
+impl crate::source::FallibleSourcePrimitive for EnvironmentPeekSource {
+    fn manifest(&self) -> &SourcePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_produce(
+        &self,
+        _parameters: &HashMap<String, crate::source::ParameterValue>,
+        ctx: &crate::source::SourceContext,
+    ) -> Result<HashMap<String, Value>, crate::source::SourceError> {
+        Ok(HashMap::from([(
+            "out".to_string(),
+            Value::Number(ctx.environment.len() as f64),
+        )]))
+    }
+}
+
+#[test]
+fn sources_see_only_their_declared_environment_key() {
+    let undeclared = ConstSource::new("peek", 0.0).manifest;
+    let mut keyed = ConstSource::new("peek_keyed", 0.0).manifest;
+    keyed.parameters = vec![crate::source::ParameterSpec {
+        name: "key".to_string(),
+        value_type: crate::source::ParameterType::String,
+        default: Some(crate::source::ParameterValue::String("count".to_string())),
+        bounds: None,
+        variants: Vec::new(),
+    }];
+    keyed.environment_key_parameter = Some("key".to_string());
+
+    let mut sources = SourceRegistry::new();
+    let mut catalog = TestCatalog::default();
+    for manifest in [undeclared, keyed] {
+        catalog.metadata.insert(
+            (manifest.id.clone(), manifest.version.clone()),
+            source_metadata(),
+        );
+        sources
+            .register(Box::new(EnvironmentPeekSource { manifest }))
+            .unwrap();
+    }
+    let output = |id: &str| crate::cluster::OutputPortSpec {
+        name: id.to_string(),
+        maps_to: crate::cluster::OutputRef {
+            node_id: id.to_string(),
+            port_name: "out".to_string(),
+        },
+    };
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            ("peek".to_string(), impl_node("peek", "peek", "v1")),
+            (
+                "peek_keyed".to_string(),
+                impl_node("peek_keyed", "peek_keyed", "v1"),
+            ),
+        ]),
+        edges: Vec::new(),
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![output("peek"), output("peek_keyed")],
+    };
+
+    assert_eq!(
+        crate::runtime::environment_keys(&expanded, &sources),
+        std::collections::BTreeSet::from(["count".to_string()])
+    );
+
+    let computes = ComputeRegistry::new();
+    let triggers = TriggerRegistry::new();
+    let actions = action::ActionRegistry::new();
+    let registries = Registries {
+        sources: &sources,
+        computes: &computes,
+        triggers: &triggers,
+        actions: &actions,
+    };
+    let ctx = ExecutionContext {
+        environment: BTreeMap::from([
+            ("count".to_string(), Value::Number(1.0)),
+            ("other".to_string(), Value::Number(2.0)),
+        ]),
+        ..ExecutionContext::default()
+    };
+    let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
+    assert_eq!(report.outputs["peek"], RuntimeValue::Number(0.0));
+    assert_eq!(report.outputs["peek_keyed"], RuntimeValue::Number(1.0));
+}
+
+#[test]
+fn environment_writes_are_reported_and_not_read_back_in_the_same_pass() {
+    let mut read = impl_node("read", "state_read_number", "0.1.0");
+    read.parameters.insert(
+        "key".to_string(),
+        crate::cluster::ParameterValue::String("count".to_string()),
+    );
+    let mut write = impl_node("write", "state_write", "0.1.0");
+    write.parameters = HashMap::from([
+        (
+            "key".to_string(),
+            crate::cluster::ParameterValue::String("count".to_string()),
+        ),
+        (
+            "number".to_string(),
+            crate::cluster::ParameterValue::Number(5.0),
+        ),
+    ]);
+    let mut fire = impl_node("fire", "const_bool", "0.1.0");
+    fire.parameters.insert(
+        "value".to_string(),
+        crate::cluster::ParameterValue::Bool(true),
+    );
+    let expanded = ExpandedGraph {
+        nodes: HashMap::from([
+            ("read".to_string(), read),
+            ("fire".to_string(), fire),
+            (
+                "emit".to_string(),
+                impl_node("emit", "emit_if_true", "0.1.0"),
+            ),
+            ("write".to_string(), write),
+        ]),
+        edges: vec![
+            port_edge("fire", "value", "emit", "input"),
+            port_edge("emit", "event", "write", "event"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "count".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "read".to_string(),
+                port_name: "value".to_string(),
+            },
+        }],
+    };
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+    let ctx = ExecutionContext {
+        environment: BTreeMap::from([("count".to_string(), Value::Number(1.0))]),
+        ..ExecutionContext::default()
+    };
+    let writes = BTreeMap::from([("count".to_string(), Value::Number(5.0))]);
+
+    let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
+    assert_eq!(report.outputs["count"], RuntimeValue::Number(1.0));
+    assert_eq!(report.environment_writes, writes);
+
+    let validated = crate::runtime::validate(&expanded, &catalog).unwrap();
+    let report = ParallelExecutor::with_workers(validated, 2)
+        .unwrap()
+        .execute(&registries, &ctx)
+        .unwrap();
+    assert_eq!(report.environment_writes, writes);
+}
+
+#[test]
+fn emit_if_all_combines_conditions_and_upstream_trigger_events() {
+    let number = |id: &str, value: f64| {
+        let mut node = impl_node(id, "number_source", "0.1.0");
+        node.parameters.insert(
+            "value".to_string(),
+            crate::cluster::ParameterValue::Number(value),
+        );
+        (id.to_string(), node)
+    };
+    let mut threshold = impl_node("above", "emit_if_above", "0.1.0");
+    threshold.parameters.insert(
+        "threshold".to_string(),
+        crate::cluster::ParameterValue::Number(2.0),
+    );
+    let graph = |b: f64| ExpandedGraph {
+        nodes: HashMap::from([
+            number("a", 3.0),
+            number("b", b),
+            ("gt".to_string(), impl_node("gt", "gt", "0.1.0")),
+            ("pass".to_string(), impl_node("pass", "add", "0.1.0")),
+            ("above".to_string(), threshold.clone()),
+            ("all".to_string(), impl_node("all", "emit_if_all", "0.1.0")),
+            ("act".to_string(), impl_node("act", "ack_action", "0.1.0")),
+        ]),
+        edges: vec![
+            port_edge("a", "value", "gt", "a"),
+            port_edge("b", "value", "gt", "b"),
+            port_edge("a", "value", "pass", "a"),
+            port_edge("b", "value", "pass", "b"),
+            port_edge("pass", "result", "above", "value"),
+            port_edge("gt", "result", "all", "conditions"),
+            port_edge("above", "event", "all", "events"),
+            port_edge("all", "event", "act", "event"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: vec![crate::cluster::OutputPortSpec {
+            name: "outcome".to_string(),
+            maps_to: crate::cluster::OutputRef {
+                node_id: "act".to_string(),
+                port_name: "outcome".to_string(),
+            },
+        }],
+    };
+    let catalog = build_core_catalog();
+    let core = core_registries().unwrap();
+    let registries = core_registries_ref(&core);
+    let outcome = |b: f64| {
+        run(
+            &graph(b),
+            &catalog,
+            &registries,
+            &ExecutionContext::default(),
+        )
+        .unwrap()
+        .outputs["outcome"]
+            .clone()
+    };
+
+    // a > b and a + b > 2 both hold.
+    assert_eq!(
+        outcome(1.0),
+        RuntimeValue::Event(RuntimeEvent::Action(action::ActionOutcome::Filled))
+    );
+    // a + b > 2 holds but a > b does not.
+    assert_eq!(
+        outcome(4.0),
+        RuntimeValue::Event(RuntimeEvent::Action(action::ActionOutcome::Skipped))
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde_contract {
+    use super::*;
+    use crate::action::ActionOutcome;
+    use crate::cluster::ParameterValue;
+    use crate::runtime::types::ExecutionReport;
+
+    /// Hello-world graph exactly as `contractTypes.ts` emits it.
+    const HELLO_WORLD_JSON: &str = r#"{
+        "nodes": {
+            "src_a": {
+                "runtime_id": "src_a",
+                "authoring_path": [],
+                "implementation": { "impl_id": "number_source", "version": "0.1.0" },
+                "parameters": { "value": { "type": "Number", "value": 3.0 } }
+            },
+            "src_b": {
+                "runtime_id": "src_b",
+                "authoring_path": [],
+                "implementation": { "impl_id": "number_source", "version": "0.1.0" },
+                "parameters": { "value": { "type": "Number", "value": 1.0 } }
+            },
+            "gt1": {
+                "runtime_id": "gt1",
+                "authoring_path": [],
+                "implementation": { "impl_id": "gt", "version": "0.1.0" },
+                "parameters": {}
+            },
+            "emit": {
+                "runtime_id": "emit",
+                "authoring_path": [],
+                "implementation": { "impl_id": "emit_if_true", "version": "0.1.0" },
+                "parameters": {}
+            },
+            "act": {
+                "runtime_id": "act",
+                "authoring_path": [],
+                "implementation": { "impl_id": "ack_action", "version": "0.1.0" },
+                "parameters": { "accept": { "type": "Bool", "value": true } }
+            }
+        },
+        "edges": [
+            {
+                "from": { "type": "NodePort", "node_id": "src_a", "port_name": "value" },
+                "to": { "type": "NodePort", "node_id": "gt1", "port_name": "a" }
+            },
+            {
+                "from": { "type": "NodePort", "node_id": "src_b", "port_name": "value" },
+                "to": { "type": "NodePort", "node_id": "gt1", "port_name": "b" }
+            },
+            {
+                "from": { "type": "NodePort", "node_id": "gt1", "port_name": "result" },
+                "to": { "type": "NodePort", "node_id": "emit", "port_name": "input" }
+            },
+            {
+                "from": { "type": "NodePort", "node_id": "emit", "port_name": "event" },
+                "to": { "type": "NodePort", "node_id": "act", "port_name": "event" }
+            }
+        ],
+        "boundary_inputs": [],
+        "boundary_outputs": [
+            { "name": "action_outcome", "maps_to": { "node_id": "act", "port_name": "outcome" } }
+        ]
+    }"#;
+
+    #[test]
+    fn contract_json_decodes_and_executes() {
+        let expanded: ExpandedGraph = serde_json::from_str(HELLO_WORLD_JSON).unwrap();
+        assert_eq!(
+            expanded.nodes["src_a"].parameters.get("value"),
+            Some(&ParameterValue::Number(3.0))
+        );
+
+        let catalog = build_core_catalog();
+        let registries = core_registries().unwrap();
+        let registries = Registries {
+            sources: &registries.sources,
+            computes: &registries.computes,
+            triggers: &registries.triggers,
+            actions: &registries.actions,
+        };
+        let ctx = ExecutionContext::default();
+
+        let report = run(&expanded, &catalog, &registries, &ctx).unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "outputs": {
+                    "action_outcome": {
+                        "type": "Event",
+                        "value": { "kind": "Action", "payload": "Filled" }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn expanded_graph_round_trips() {
+        let expanded: ExpandedGraph = serde_json::from_str(HELLO_WORLD_JSON).unwrap();
+        let encoded = serde_json::to_string(&expanded).unwrap();
+        let decoded: ExpandedGraph = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded, expanded);
+    }
+
+    #[test]
+    fn external_input_endpoint_is_tagged() {
+        let endpoint = ExpandedEndpoint::ExternalInput {
+            name: "price".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&endpoint).unwrap(),
+            serde_json::json!({ "type": "ExternalInput", "name": "price" })
+        );
+    }
+
+    #[test]
+    fn parameter_values_use_adjacent_tagging() {
+        let cases = [
+            (
+                ParameterValue::Int(7),
+                serde_json::json!({ "type": "Int", "value": 7 }),
+            ),
+            (
+                ParameterValue::Enum("fast".to_string()),
+                serde_json::json!({ "type": "Enum", "value": "fast" }),
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(serde_json::to_value(&value).unwrap(), expected);
+            let decoded: ParameterValue = serde_json::from_value(expected).unwrap();
+            assert_eq!(decoded, value);
+        }
+    }
+
+    #[test]
+    fn execution_report_round_trips() {
+        let report = ExecutionReport {
+            outputs: HashMap::from([
+                ("n".to_string(), RuntimeValue::Number(1.5)),
+                ("s".to_string(), RuntimeValue::Series(vec![1.0, 2.0])),
+                (
+                    "e".to_string(),
+                    RuntimeValue::Event(RuntimeEvent::Trigger(
+                        crate::trigger::TriggerEvent::NotEmitted,
+                    )),
+                ),
+                (
+                    "a".to_string(),
+                    RuntimeValue::Event(RuntimeEvent::Action(ActionOutcome::Skipped)),
+                ),
+            ]),
+            trace: None,
+            compute_state: HashMap::new(),
+            environment_writes: BTreeMap::from([("armed".to_string(), Value::Bool(true))]),
+        };
+        let encoded = serde_json::to_string(&report).unwrap();
+        let decoded: ExecutionReport = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(decoded.outputs, report.outputs);
+        assert_eq!(decoded.environment_writes, report.environment_writes);
+    }
+}
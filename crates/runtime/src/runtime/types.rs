@@ -1,24 +1,34 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::action::{ActionRegistry, ActionValidationError};
-use crate::cluster::{InputMetadata, OutputMetadata, PrimitiveKind, ValueType};
-use crate::compute::PrimitiveRegistry as ComputeRegistry;
+use crate::cluster::{
+    InputMetadata, OutputMetadata, OutputRef, ParameterType, PrimitiveKind, ValueType,
+};
+use crate::common::bounds::BoundsError;
+use crate::common::Value;
+use crate::compute::{PrimitiveRegistry as ComputeRegistry, PrimitiveState};
 use crate::source::SourceRegistry;
 use crate::trigger::{TriggerRegistry, TriggerState};
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", content = "payload"))]
 pub enum RuntimeEvent {
     Trigger(crate::trigger::TriggerEvent),
     Action(crate::action::ActionOutcome),
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum RuntimeValue {
     Number(f64),
     Series(Vec<f64>),
     Bool(bool),
     Event(RuntimeEvent),
     String(String),
+    /// Values of a `Cardinality::Multiple` input, in edge order.
+    List(Vec<RuntimeValue>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -30,6 +40,7 @@ pub struct ValidatedNode {
     /// Input metadata is used for validation only (required + type checks).
     pub inputs: Vec<InputMetadata>,
     pub outputs: HashMap<String, OutputMetadata>,
+    /// Every declared parameter, with manifest defaults filled in for unbound ones.
     pub parameters: HashMap<String, crate::cluster::ParameterValue>,
 }
 
@@ -49,12 +60,34 @@ pub struct ValidatedGraph {
     pub nodes: HashMap<String, ValidatedNode>,
     pub edges: Vec<ValidatedEdge>,
     pub topo_order: Vec<String>,
-    pub boundary_outputs: Vec<crate::cluster::OutputPortSpec>,
+    pub boundary_outputs: Vec<ValidatedOutput>,
 }
 
-#[derive(Debug)]
+/// A boundary output resolved against the declared outputs of the node it reads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatedOutput {
+    pub name: String,
+    pub maps_to: OutputRef,
+    pub value_type: ValueType,
+}
+
+impl ValidatedGraph {
+    /// The graph's output signature: each boundary output name with its value type,
+    /// in declaration order.
+    pub fn output_signature(&self) -> Vec<(&str, &ValueType)> {
+        self.boundary_outputs
+            .iter()
+            .map(|out| (out.name.as_str(), &out.value_type))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum ValidationError {
-    CycleDetected,
+    /// Node ids of one strongly connected component, sorted.
+    CycleDetected {
+        nodes: Vec<String>,
+    },
     UnknownNode(String),
     MissingPrimitive {
         id: String,
@@ -88,20 +121,190 @@ pub enum ValidationError {
     ExternalInputNotAllowed {
         name: String,
     },
+    UnknownParameter {
+        node: String,
+        parameter: String,
+    },
+    ParameterTypeMismatch {
+        node: String,
+        parameter: String,
+        expected: ParameterType,
+        got: ParameterType,
+    },
+    MissingRequiredParameter {
+        node: String,
+        parameter: String,
+    },
+    /// The catalog declares bounds that do not fit the parameter type.
+    InvalidParameterBounds {
+        node: String,
+        parameter: String,
+        error: BoundsError,
+    },
+    ParameterOutOfBounds {
+        node: String,
+        parameter: String,
+        bounds: String,
+    },
+    /// An Enum parameter is set to a value outside its declared variants.
+    UnknownEnumVariant {
+        node: String,
+        parameter: String,
+        variant: String,
+    },
+    /// A boundary output names a node that is not in the graph.
+    UnknownBoundaryNode {
+        output: String,
+        node: String,
+    },
+    /// A boundary output names a port its node does not declare.
+    UnknownBoundaryPort {
+        output: String,
+        node: String,
+        port: String,
+    },
+    /// More than one edge targets an input declared `Cardinality::Single`.
+    MultipleEdgesIntoSingleInput {
+        node: String,
+        input: String,
+    },
+}
+
+/// A validation failure located in the expanded graph.
+/// `edge` indexes `ExpandedGraph::edges`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub error: ValidationError,
+    pub node: Option<String>,
+    pub port: Option<String>,
+    pub edge: Option<usize>,
+}
+
+impl Diagnostic {
+    pub(crate) fn node(error: ValidationError, node: &str) -> Self {
+        Self {
+            error,
+            node: Some(node.to_string()),
+            port: None,
+            edge: None,
+        }
+    }
+
+    pub(crate) fn edge(error: ValidationError, edge: usize) -> Self {
+        Self {
+            error,
+            node: None,
+            port: None,
+            edge: Some(edge),
+        }
+    }
 }
 
 #[derive(Debug)]
 pub enum ExecError {
-    UnknownPrimitive { id: String, version: String },
-    TypeConversionFailed { node: String, port: String },
-    ParameterTypeConversionFailed { node: String, parameter: String },
+    UnknownPrimitive {
+        id: String,
+        version: String,
+    },
+    TypeConversionFailed {
+        node: String,
+        port: String,
+    },
+    ParameterTypeConversionFailed {
+        node: String,
+        parameter: String,
+    },
     ActionExecutionFailed(ActionValidationError),
-    MissingOutput { node: String, output: String },
+    MissingOutput {
+        node: String,
+        output: String,
+    },
+    SourceFailed {
+        node: String,
+        error: crate::source::SourceError,
+    },
+    ComputeFailed {
+        node: String,
+        error: crate::compute::ComputeError,
+    },
+    TriggerFailed {
+        node: String,
+        error: crate::trigger::TriggerError,
+    },
+    ActionFailed {
+        node: String,
+        error: crate::action::ActionError,
+    },
+    /// A primitive panicked; the panic was caught at the primitive call boundary.
+    PrimitivePanicked {
+        node: String,
+        impl_id: String,
+        message: String,
+    },
+    /// `ExecutionContext::compute_state` carried state for a node that is not a
+    /// Compute primitive declaring `stateful: true`.
+    UndeclaredState {
+        node: String,
+    },
+    /// A Compute node produced NaN or an infinity under `NonFinitePolicy::Error`.
+    NonFiniteValue {
+        node: String,
+        port: String,
+    },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ExecutionContext {
     pub trigger_state: HashMap<String, TriggerState>,
+    /// Declared state of stateful Compute nodes, keyed by runtime node id.
+    /// Nodes without an entry start from `PrimitiveState::default()`.
+    pub compute_state: HashMap<String, PrimitiveState>,
+    /// Opt-in: record a `NodeTrace` for every node in `ExecutionReport::trace`.
+    pub trace: bool,
+    /// Payload of the external event this pass processes. Only Source primitives read
+    /// it, through `SourceContext`; it never reaches a node input directly.
+    pub payload: Vec<u8>,
+    /// How NaN and infinite Compute outputs are handled.
+    pub non_finite: NonFinitePolicy,
+    /// Environment state as of the start of this pass. Only Source primitives read it,
+    /// through `SourceContext`; writes made by Actions come back in
+    /// `ExecutionReport::environment_writes` and are never visible within the pass.
+    pub environment: BTreeMap<String, Value>,
+}
+
+/// Applied to every Number and every Series element a Compute node outputs, before
+/// any downstream node reads it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum NonFinitePolicy {
+    /// Pass non-finite values through unchanged.
+    #[default]
+    Propagate,
+    /// Fail the pass with `ExecError::NonFiniteValue`.
+    Error,
+    /// Replace each non-finite value with this one.
+    Substitute(FiniteNumber),
+}
+
+impl NonFinitePolicy {
+    /// `Substitute` with `value`, or `None` if `value` is itself NaN or infinite.
+    pub fn substitute(value: f64) -> Option<Self> {
+        FiniteNumber::new(value).map(NonFinitePolicy::Substitute)
+    }
+}
+
+/// A Number known to be neither NaN nor infinite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FiniteNumber(f64);
+
+impl FiniteNumber {
+    /// `None` if `value` is NaN or infinite.
+    pub fn new(value: f64) -> Option<Self> {
+        value.is_finite().then_some(FiniteNumber(value))
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
 }
 
 pub struct Registries<'a> {
@@ -112,8 +315,45 @@ pub struct Registries<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExecutionReport {
     pub outputs: HashMap<String, RuntimeValue>,
+    /// Present only when `ExecutionContext::trace` was set. One entry per node, in `topo_order`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub trace: Option<Vec<NodeTrace>>,
+    /// State of every stateful Compute node after this pass; feed it back through
+    /// `ExecutionContext::compute_state` to carry it into the next pass.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "HashMap::is_empty")
+    )]
+    pub compute_state: HashMap<String, PrimitiveState>,
+    /// Environment state written by this pass's Actions. When two Actions write the
+    /// same key, the later one in `topo_order` wins. The host applies the writes after
+    /// the pass, so they become visible from the next pass on.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub environment_writes: BTreeMap<String, Value>,
+}
+
+/// What a single node saw and produced during one execution pass.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeTrace {
+    pub node: String,
+    pub impl_id: String,
+    pub version: String,
+    pub kind: PrimitiveKind,
+    pub inputs: HashMap<String, RuntimeValue>,
+    pub parameters: HashMap<String, crate::cluster::ParameterValue>,
+    pub outputs: HashMap<String, RuntimeValue>,
+    /// R.7: the action was not executed because a trigger input was NotEmitted.
+    pub skipped: bool,
 }
 
 impl RuntimeValue {
@@ -124,6 +364,10 @@ impl RuntimeValue {
             RuntimeValue::Bool(_) => ValueType::Bool,
             RuntimeValue::Event(_) => ValueType::Event,
             RuntimeValue::String(_) => ValueType::String,
+            // A list is typed by its items; the runtime never builds an empty one.
+            RuntimeValue::List(items) => items
+                .first()
+                .map_or(ValueType::Series, RuntimeValue::value_type),
         }
     }
 }
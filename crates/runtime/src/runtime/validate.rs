@@ -10,72 +10,182 @@
 // DO NOT introduce alternative input paths.
 // ===============================
 
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-use crate::cluster::{ExpandedEndpoint, ExpandedGraph, PrimitiveCatalog, PrimitiveKind, ValueType};
+use crate::cluster::{
+    Cardinality, ExpandedEndpoint, ExpandedGraph, ParameterSpec, ParameterValue, PrimitiveCatalog,
+    PrimitiveKind, ValueType,
+};
+use crate::common::bounds::BoundsError;
 
-use super::types::{Endpoint, ValidatedEdge, ValidatedGraph, ValidatedNode, ValidationError};
+use super::types::{
+    Diagnostic, Endpoint, ValidatedEdge, ValidatedGraph, ValidatedNode, ValidatedOutput,
+    ValidationError,
+};
 
+/// Fail-fast validation. Returns the first diagnostic `validate_all` would report.
 pub fn validate<C: PrimitiveCatalog>(
     expanded: &ExpandedGraph,
     catalog: &C,
 ) -> Result<ValidatedGraph, ValidationError> {
+    validate_all(expanded, catalog).map_err(|mut diagnostics| diagnostics.swap_remove(0).error)
+}
+
+/// Collect-all validation. Runs every pass and reports every failure, in pass order:
+/// primitives and their parameters, edge endpoints, boundary outputs, cycles, wiring
+/// matrix, required inputs, input cardinality, types, action gating.
+/// Within a pass, node diagnostics are ordered by node id, edge diagnostics by edge
+/// index, and boundary-output diagnostics by declaration order.
+pub fn validate_all<C: PrimitiveCatalog>(
+    expanded: &ExpandedGraph,
+    catalog: &C,
+) -> Result<ValidatedGraph, Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
     let mut nodes: HashMap<String, ValidatedNode> = HashMap::new();
 
-    for (id, node) in &expanded.nodes {
-        let meta = catalog
-            .get(&node.implementation.impl_id, &node.implementation.version)
-            .ok_or_else(|| ValidationError::MissingPrimitive {
-                id: node.implementation.impl_id.clone(),
-                version: node.implementation.version.clone(),
-            })?;
+    let ids: BTreeSet<&String> = expanded.nodes.keys().collect();
+    for id in &ids {
+        let node = &expanded.nodes[*id];
+        let Some(meta) = catalog.get(&node.implementation.impl_id, &node.implementation.version)
+        else {
+            diagnostics.push(Diagnostic::node(
+                ValidationError::MissingPrimitive {
+                    id: node.implementation.impl_id.clone(),
+                    version: node.implementation.version.clone(),
+                },
+                id,
+            ));
+            continue;
+        };
+        let parameters =
+            resolve_parameters(id, &node.parameters, &meta.parameters, &mut diagnostics);
 
         nodes.insert(
-            id.clone(),
+            (*id).clone(),
             ValidatedNode {
-                runtime_id: id.clone(),
+                runtime_id: (*id).clone(),
                 impl_id: node.implementation.impl_id.clone(),
                 version: node.implementation.version.clone(),
                 kind: meta.kind.clone(),
                 inputs: meta.inputs.clone(),
                 outputs: meta.outputs.clone(),
-                parameters: node.parameters.clone(),
+                parameters,
             },
         );
     }
 
-    let edges: Vec<ValidatedEdge> = expanded
-        .edges
-        .iter()
-        .map(|e| {
-            if let ExpandedEndpoint::ExternalInput { name } = &e.from {
-                return Err(ValidationError::ExternalInputNotAllowed { name: name.clone() });
+    // Edges keep their position in `expanded.edges` so diagnostics can point at them.
+    let mut edges: Vec<(usize, ValidatedEdge)> = Vec::new();
+    for (index, e) in expanded.edges.iter().enumerate() {
+        let mut external = false;
+        for endpoint in [&e.from, &e.to] {
+            if let ExpandedEndpoint::ExternalInput { name } = endpoint {
+                diagnostics.push(Diagnostic::edge(
+                    ValidationError::ExternalInputNotAllowed { name: name.clone() },
+                    index,
+                ));
+                external = true;
             }
-            if let ExpandedEndpoint::ExternalInput { name } = &e.to {
-                return Err(ValidationError::ExternalInputNotAllowed { name: name.clone() });
+        }
+        if external {
+            continue;
+        }
+
+        let edge = ValidatedEdge {
+            from: map_endpoint(&e.from),
+            to: map_endpoint(&e.to),
+        };
+        let mut endpoints_known = true;
+        for Endpoint::NodePort { node_id, .. } in [&edge.from, &edge.to] {
+            if !expanded.nodes.contains_key(node_id) {
+                diagnostics.push(Diagnostic {
+                    error: ValidationError::UnknownNode(node_id.clone()),
+                    node: Some(node_id.clone()),
+                    port: None,
+                    edge: Some(index),
+                });
+                endpoints_known = false;
             }
-            Ok(ValidatedEdge {
-                from: map_endpoint(&e.from),
-                to: map_endpoint(&e.to),
-            })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+        }
+        if endpoints_known {
+            edges.push((index, edge));
+        }
+    }
+
+    let mut boundary_outputs = resolve_boundary_outputs(expanded, &nodes, &mut diagnostics);
+    let topo_order = topological_sort(&nodes, &edges, &mut diagnostics);
 
-    let topo_order = topological_sort(&nodes, &edges)?;
+    enforce_wiring_matrix(&nodes, &edges, &mut diagnostics);
+    enforce_required_inputs(&nodes, &edges, &mut diagnostics);
+    enforce_cardinality(&nodes, &edges, &mut diagnostics);
+    enforce_types(&mut nodes, &edges, &topo_order, &mut diagnostics);
+    enforce_action_gating(&nodes, &edges, &mut diagnostics);
 
-    enforce_wiring_matrix(&nodes, &edges)?;
-    enforce_required_inputs(&nodes, &edges)?;
-    enforce_types(&nodes, &edges)?;
-    enforce_action_gating(&nodes, &edges)?;
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    // Boundary outputs were typed from the manifests; pick up bound type variables.
+    for out in &mut boundary_outputs {
+        out.value_type = nodes[&out.maps_to.node_id].outputs[&out.maps_to.port_name]
+            .value_type
+            .clone();
+    }
 
     Ok(ValidatedGraph {
         nodes,
-        edges,
+        edges: edges.into_iter().map(|(_, edge)| edge).collect(),
         topo_order,
-        boundary_outputs: expanded.boundary_outputs.clone(),
+        boundary_outputs,
     })
 }
 
+/// Resolves each boundary output to a declared output port and records its type.
+/// Outputs of nodes whose primitive is missing are dropped without a diagnostic of
+/// their own; the missing primitive is already reported.
+fn resolve_boundary_outputs(
+    expanded: &ExpandedGraph,
+    nodes: &HashMap<String, ValidatedNode>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<ValidatedOutput> {
+    let mut resolved = Vec::new();
+    for out in &expanded.boundary_outputs {
+        let node_id = &out.maps_to.node_id;
+        let port = &out.maps_to.port_name;
+        if !expanded.nodes.contains_key(node_id) {
+            diagnostics.push(Diagnostic::node(
+                ValidationError::UnknownBoundaryNode {
+                    output: out.name.clone(),
+                    node: node_id.clone(),
+                },
+                node_id,
+            ));
+            continue;
+        }
+        let Some(node) = nodes.get(node_id) else {
+            continue;
+        };
+        match node.outputs.get(port) {
+            Some(meta) => resolved.push(ValidatedOutput {
+                name: out.name.clone(),
+                maps_to: out.maps_to.clone(),
+                value_type: meta.value_type.clone(),
+            }),
+            None => diagnostics.push(Diagnostic {
+                error: ValidationError::UnknownBoundaryPort {
+                    output: out.name.clone(),
+                    node: node_id.clone(),
+                    port: port.clone(),
+                },
+                node: Some(node_id.clone()),
+                port: Some(port.clone()),
+                edge: None,
+            }),
+        }
+    }
+    resolved
+}
+
 fn map_endpoint(ep: &ExpandedEndpoint) -> Endpoint {
     match ep {
         ExpandedEndpoint::NodePort { node_id, port_name } => Endpoint::NodePort {
@@ -88,19 +198,35 @@ fn map_endpoint(ep: &ExpandedEndpoint) -> Endpoint {
     }
 }
 
+/// Passes below only look at edges whose endpoints both resolved to catalog primitives;
+/// anything else has already been reported.
+fn resolved<'a>(
+    nodes: &'a HashMap<String, ValidatedNode>,
+    edge: &ValidatedEdge,
+) -> Option<(&'a ValidatedNode, &'a ValidatedNode)> {
+    let Endpoint::NodePort { node_id: from, .. } = &edge.from;
+    let Endpoint::NodePort { node_id: to, .. } = &edge.to;
+    Some((nodes.get(from)?, nodes.get(to)?))
+}
+
 fn topological_sort(
     nodes: &HashMap<String, ValidatedNode>,
-    edges: &[ValidatedEdge],
-) -> Result<Vec<String>, ValidationError> {
+    edges: &[(usize, ValidatedEdge)],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<String> {
     let mut in_degree: HashMap<String, usize> = nodes.keys().map(|k| (k.clone(), 0)).collect();
     let mut dependents: HashMap<String, Vec<String>> =
         nodes.keys().map(|k| (k.clone(), vec![])).collect();
 
-    for edge in edges {
-        let Endpoint::NodePort { node_id: from, .. } = &edge.from;
-        let Endpoint::NodePort { node_id: to, .. } = &edge.to;
-        *in_degree.get_mut(to).unwrap() += 1;
-        dependents.get_mut(from).unwrap().push(to.clone());
+    for (_, edge) in edges {
+        let Some((from, to)) = resolved(nodes, edge) else {
+            continue;
+        };
+        *in_degree.get_mut(&to.runtime_id).unwrap() += 1;
+        dependents
+            .get_mut(&from.runtime_id)
+            .unwrap()
+            .push(to.runtime_id.clone());
     }
 
     let mut queue: BTreeSet<String> = in_degree
@@ -127,45 +253,233 @@ fn topological_sort(
     }
 
     if sorted.len() != nodes.len() {
-        return Err(ValidationError::CycleDetected);
+        // Nodes left over are either on a cycle or downstream of one.
+        // Only the strongly connected components that actually loop are reported.
+        let sorted_set: HashSet<&String> = sorted.iter().collect();
+        let remaining: BTreeMap<&String, Vec<&String>> = dependents
+            .iter()
+            .filter(|(id, _)| !sorted_set.contains(id))
+            .map(|(id, deps)| (id, deps.iter().collect()))
+            .collect();
+
+        for cycle in cycles(&remaining) {
+            diagnostics.push(Diagnostic {
+                node: cycle.first().cloned(),
+                error: ValidationError::CycleDetected { nodes: cycle },
+                port: None,
+                edge: None,
+            });
+        }
     }
 
-    Ok(sorted)
+    sorted
+}
+
+/// Tarjan's strongly connected components over `graph`, keeping only components
+/// that form a cycle (more than one node, or a self-loop). Node ids within a cycle
+/// are sorted, and cycles are ordered by their first node id.
+fn cycles(graph: &BTreeMap<&String, Vec<&String>>) -> Vec<Vec<String>> {
+    struct Tarjan<'a, 'g> {
+        graph: &'g BTreeMap<&'a String, Vec<&'a String>>,
+        index: HashMap<&'a String, usize>,
+        low: HashMap<&'a String, usize>,
+        stack: Vec<&'a String>,
+        on_stack: HashSet<&'a String>,
+        next: usize,
+        components: Vec<Vec<String>>,
+    }
+
+    impl<'a> Tarjan<'a, '_> {
+        fn visit(&mut self, v: &'a String) {
+            self.index.insert(v, self.next);
+            self.low.insert(v, self.next);
+            self.next += 1;
+            self.stack.push(v);
+            self.on_stack.insert(v);
+
+            for &w in self.graph.get(v).into_iter().flatten() {
+                if !self.graph.contains_key(w) {
+                    continue;
+                }
+                if !self.index.contains_key(w) {
+                    self.visit(w);
+                    let low = self.low[v].min(self.low[w]);
+                    self.low.insert(v, low);
+                } else if self.on_stack.contains(w) {
+                    let low = self.low[v].min(self.index[w]);
+                    self.low.insert(v, low);
+                }
+            }
+
+            if self.low[v] == self.index[v] {
+                let mut component = Vec::new();
+                while let Some(w) = self.stack.pop() {
+                    self.on_stack.remove(w);
+                    component.push(w.clone());
+                    if w == v {
+                        break;
+                    }
+                }
+                let self_loop = self.graph[v].contains(&v);
+                if component.len() > 1 || self_loop {
+                    component.sort();
+                    self.components.push(component);
+                }
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        graph,
+        index: HashMap::new(),
+        low: HashMap::new(),
+        stack: Vec::new(),
+        on_stack: HashSet::new(),
+        next: 0,
+        components: Vec::new(),
+    };
+    for &v in graph.keys() {
+        if !tarjan.index.contains_key(v) {
+            tarjan.visit(v);
+        }
+    }
+
+    let mut components = tarjan.components;
+    components.sort();
+    components
+}
+
+/// Checks a node's bound parameters against the primitive's declarations and fills in
+/// defaults, so execution sees every declared parameter with its declared type.
+fn resolve_parameters(
+    node: &str,
+    bound: &HashMap<String, ParameterValue>,
+    declared: &[ParameterSpec],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> HashMap<String, ParameterValue> {
+    let mut unknown: Vec<&String> = bound
+        .keys()
+        .filter(|name| !declared.iter().any(|spec| spec.name == **name))
+        .collect();
+    unknown.sort();
+    for name in unknown {
+        diagnostics.push(Diagnostic::node(
+            ValidationError::UnknownParameter {
+                node: node.to_string(),
+                parameter: name.clone(),
+            },
+            node,
+        ));
+    }
+
+    let mut parameters = HashMap::new();
+    for spec in declared {
+        match bound.get(&spec.name).or(spec.default.as_ref()) {
+            Some(value) => {
+                let got = value.value_type();
+                if got != spec.ty {
+                    diagnostics.push(Diagnostic::node(
+                        ValidationError::ParameterTypeMismatch {
+                            node: node.to_string(),
+                            parameter: spec.name.clone(),
+                            expected: spec.ty.clone(),
+                            got,
+                        },
+                        node,
+                    ));
+                } else {
+                    check_parameter_value(node, spec, value, diagnostics);
+                }
+                parameters.insert(spec.name.clone(), value.clone());
+            }
+            None => diagnostics.push(Diagnostic::node(
+                ValidationError::MissingRequiredParameter {
+                    node: node.to_string(),
+                    parameter: spec.name.clone(),
+                },
+                node,
+            )),
+        }
+    }
+    parameters
+}
+
+/// Checks a well-typed parameter value against the spec's variants and bounds.
+fn check_parameter_value(
+    node: &str,
+    spec: &ParameterSpec,
+    value: &ParameterValue,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let ParameterValue::Enum(variant) = value {
+        if !spec.variants.contains(variant) {
+            diagnostics.push(Diagnostic::node(
+                ValidationError::UnknownEnumVariant {
+                    node: node.to_string(),
+                    parameter: spec.name.clone(),
+                    variant: variant.clone(),
+                },
+                node,
+            ));
+        }
+    }
+
+    let Some(bounds) = &spec.bounds else {
+        return;
+    };
+    if spec.ty.bounded_kind() != Some(bounds.kind()) {
+        diagnostics.push(Diagnostic::node(
+            ValidationError::InvalidParameterBounds {
+                node: node.to_string(),
+                parameter: spec.name.clone(),
+                error: BoundsError::NotApplicable,
+            },
+            node,
+        ));
+    } else if !value.bounded().is_some_and(|v| bounds.admits(v)) {
+        diagnostics.push(Diagnostic::node(
+            ValidationError::ParameterOutOfBounds {
+                node: node.to_string(),
+                parameter: spec.name.clone(),
+                bounds: bounds.to_string(),
+            },
+            node,
+        ));
+    }
 }
 
 fn enforce_wiring_matrix(
     nodes: &HashMap<String, ValidatedNode>,
-    edges: &[ValidatedEdge],
-) -> Result<(), ValidationError> {
-    for edge in edges {
-        let Endpoint::NodePort { node_id: from, .. } = &edge.from;
-        let Endpoint::NodePort { node_id: to, .. } = &edge.to;
-
-        let from_kind = &nodes
-            .get(from)
-            .ok_or_else(|| ValidationError::UnknownNode(from.clone()))?
-            .kind;
-        let to_kind = &nodes
-            .get(to)
-            .ok_or_else(|| ValidationError::UnknownNode(to.clone()))?
-            .kind;
-
-        if !wiring_allowed(from_kind, to_kind) {
-            return Err(ValidationError::InvalidEdgeKind {
-                from: from_kind.clone(),
-                to: to_kind.clone(),
+    edges: &[(usize, ValidatedEdge)],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (index, edge) in edges {
+        let Some((from, to)) = resolved(nodes, edge) else {
+            continue;
+        };
+
+        if !wiring_allowed(&from.kind, &to.kind) {
+            let Endpoint::NodePort { port_name, .. } = &edge.to;
+            diagnostics.push(Diagnostic {
+                error: ValidationError::InvalidEdgeKind {
+                    from: from.kind.clone(),
+                    to: to.kind.clone(),
+                },
+                node: Some(to.runtime_id.clone()),
+                port: Some(port_name.clone()),
+                edge: Some(*index),
             });
         }
     }
-    Ok(())
 }
 
 fn enforce_required_inputs(
     nodes: &HashMap<String, ValidatedNode>,
-    edges: &[ValidatedEdge],
-) -> Result<(), ValidationError> {
+    edges: &[(usize, ValidatedEdge)],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
     let mut incoming: HashMap<(&String, &str), bool> = HashMap::new();
-    for edge in edges {
+    for (_, edge) in edges {
         let Endpoint::NodePort {
             node_id: to,
             port_name,
@@ -173,24 +487,71 @@ fn enforce_required_inputs(
         incoming.insert((to, port_name.as_str()), true);
     }
 
-    for node in nodes.values() {
+    for node in sorted_nodes(nodes) {
         for input in node.required_inputs() {
             if !incoming.contains_key(&(&node.runtime_id, input.name.as_str())) {
-                return Err(ValidationError::MissingRequiredInput {
-                    node: node.runtime_id.clone(),
-                    input: input.name.clone(),
+                diagnostics.push(Diagnostic {
+                    error: ValidationError::MissingRequiredInput {
+                        node: node.runtime_id.clone(),
+                        input: input.name.clone(),
+                    },
+                    node: Some(node.runtime_id.clone()),
+                    port: Some(input.name.clone()),
+                    edge: None,
                 });
             }
         }
     }
-    Ok(())
 }
 
-fn enforce_types(
+/// A `Single` input accepts one edge. Every edge after the first into such a port is
+/// reported, since executing it would silently overwrite the earlier value.
+fn enforce_cardinality(
     nodes: &HashMap<String, ValidatedNode>,
-    edges: &[ValidatedEdge],
-) -> Result<(), ValidationError> {
-    for edge in edges {
+    edges: &[(usize, ValidatedEdge)],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut seen: HashSet<(&String, &String)> = HashSet::new();
+    for (index, edge) in edges {
+        let Endpoint::NodePort {
+            node_id: to,
+            port_name,
+        } = &edge.to;
+        let Some(node) = nodes.get(to) else {
+            continue;
+        };
+        let single = node
+            .inputs
+            .iter()
+            .find(|i| i.name == *port_name)
+            .is_some_and(|i| i.cardinality == Cardinality::Single);
+        if single && !seen.insert((to, port_name)) {
+            diagnostics.push(Diagnostic {
+                error: ValidationError::MultipleEdgesIntoSingleInput {
+                    node: to.clone(),
+                    input: port_name.clone(),
+                },
+                node: Some(to.clone()),
+                port: Some(port_name.clone()),
+                edge: Some(*index),
+            });
+        }
+    }
+}
+
+/// Binds every node's type variables, then checks each edge's types.
+fn enforce_types(
+    nodes: &mut HashMap<String, ValidatedNode>,
+    edges: &[(usize, ValidatedEdge)],
+    topo_order: &[String],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    bind_type_variables(nodes, edges, topo_order);
+
+    for (index, edge) in edges {
+        let Some((from_node, to_node)) = resolved(nodes, edge) else {
+            continue;
+        };
         let Endpoint::NodePort {
             node_id: from,
             port_name: from_port,
@@ -200,97 +561,173 @@ fn enforce_types(
             port_name: to_port,
         } = &edge.to;
 
-        let from_node = nodes
-            .get(from)
-            .ok_or_else(|| ValidationError::UnknownNode(from.clone()))?;
-        let to_node = nodes
-            .get(to)
-            .ok_or_else(|| ValidationError::UnknownNode(to.clone()))?;
-
         let from_type = from_node
             .outputs
             .get(from_port)
-            .ok_or_else(|| ValidationError::MissingOutputMetadata {
-                node: from.clone(),
-                output: from_port.clone(),
-            })?
-            .value_type
-            .clone();
-
+            .map(|o| o.value_type.clone());
         let expected = to_node
             .inputs
             .iter()
             .find(|i| i.name == *to_port)
-            .ok_or_else(|| ValidationError::MissingInputMetadata {
-                node: to.clone(),
-                input: to_port.clone(),
-            })?
-            .value_type
-            .clone();
-
-        if from_type != expected {
-            return Err(ValidationError::TypeMismatch {
-                from: from.clone(),
-                output: from_port.clone(),
-                to: to.clone(),
-                input: to_port.clone(),
-                expected,
-                got: from_type,
+            .map(|i| i.value_type.clone());
+
+        if from_type.is_none() {
+            diagnostics.push(Diagnostic {
+                error: ValidationError::MissingOutputMetadata {
+                    node: from.clone(),
+                    output: from_port.clone(),
+                },
+                node: Some(from.clone()),
+                port: Some(from_port.clone()),
+                edge: Some(*index),
             });
         }
+        if expected.is_none() {
+            diagnostics.push(Diagnostic {
+                error: ValidationError::MissingInputMetadata {
+                    node: to.clone(),
+                    input: to_port.clone(),
+                },
+                node: Some(to.clone()),
+                port: Some(to_port.clone()),
+                edge: Some(*index),
+            });
+        }
+
+        if let (Some(from_type), Some(expected)) = (from_type, expected) {
+            if from_type != expected {
+                diagnostics.push(Diagnostic {
+                    error: ValidationError::TypeMismatch {
+                        from: from.clone(),
+                        output: from_port.clone(),
+                        to: to.clone(),
+                        input: to_port.clone(),
+                        expected,
+                        got: from_type,
+                    },
+                    node: Some(to.clone()),
+                    port: Some(to_port.clone()),
+                    edge: Some(*index),
+                });
+            }
+        }
     }
+}
+
+/// A type variable takes the type of the first edge, in edge order, that feeds an input
+/// declared with it, and the node's ports are rewritten to that type. Any other edge
+/// into the same variable then surfaces as a `TypeMismatch` against the bound type.
+/// Nodes bind in topological order so a bound output can in turn bind the variables of
+/// the node it feeds; an output that is still a variable binds nothing.
+fn bind_type_variables(
+    nodes: &mut HashMap<String, ValidatedNode>,
+    edges: &[(usize, ValidatedEdge)],
+    topo_order: &[String],
+) {
+    let mut incoming: HashMap<&str, Vec<&ValidatedEdge>> = HashMap::new();
+    for (_, edge) in edges {
+        let Endpoint::NodePort { node_id: to, .. } = &edge.to;
+        incoming.entry(to.as_str()).or_default().push(edge);
+    }
+
+    for id in topo_order {
+        let mut bindings: HashMap<char, ValueType> = HashMap::new();
+        for edge in incoming.get(id.as_str()).into_iter().flatten() {
+            let Some((from_node, to_node)) = resolved(nodes, edge) else {
+                continue;
+            };
+            let Endpoint::NodePort {
+                port_name: from_port,
+                ..
+            } = &edge.from;
+            let Endpoint::NodePort {
+                port_name: to_port, ..
+            } = &edge.to;
+            let Some(ValueType::Var(variable)) = to_node
+                .inputs
+                .iter()
+                .find(|i| i.name == *to_port)
+                .map(|i| &i.value_type)
+            else {
+                continue;
+            };
+            match from_node.outputs.get(from_port).map(|o| &o.value_type) {
+                None | Some(ValueType::Var(_)) => {}
+                Some(bound) => {
+                    bindings.entry(*variable).or_insert_with(|| bound.clone());
+                }
+            }
+        }
 
-    Ok(())
+        let Some(node) = nodes.get_mut(id).filter(|_| !bindings.is_empty()) else {
+            continue;
+        };
+        let bind = |ty: &mut ValueType| {
+            if let ValueType::Var(variable) = ty {
+                if let Some(bound) = bindings.get(variable) {
+                    *ty = bound.clone();
+                }
+            }
+        };
+        node.inputs.iter_mut().for_each(|i| bind(&mut i.value_type));
+        node.outputs
+            .values_mut()
+            .for_each(|o| bind(&mut o.value_type));
+    }
 }
 
 fn enforce_action_gating(
     nodes: &HashMap<String, ValidatedNode>,
-    edges: &[ValidatedEdge],
-) -> Result<(), ValidationError> {
+    edges: &[(usize, ValidatedEdge)],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
     let mut action_inputs: HashMap<String, bool> = HashMap::new();
 
-    for edge in edges {
-        let Endpoint::NodePort { node_id: to, .. } = &edge.to;
-        if let Some(target) = nodes.get(to) {
-            if target.kind == PrimitiveKind::Action {
-                let Endpoint::NodePort {
-                    node_id: from,
-                    port_name: from_port,
-                } = &edge.from;
-                if let Some(src) = nodes.get(from) {
-                    if src.kind == PrimitiveKind::Trigger {
-                        if let Some(meta) = src.outputs.get(from_port) {
-                            if meta.value_type == ValueType::Event {
-                                action_inputs.insert(to.clone(), true);
-                            }
-                        }
-                    }
+    for (_, edge) in edges {
+        let Some((src, target)) = resolved(nodes, edge) else {
+            continue;
+        };
+        if target.kind == PrimitiveKind::Action && src.kind == PrimitiveKind::Trigger {
+            let Endpoint::NodePort {
+                port_name: from_port,
+                ..
+            } = &edge.from;
+            if let Some(meta) = src.outputs.get(from_port) {
+                if meta.value_type == ValueType::Event {
+                    action_inputs.insert(target.runtime_id.clone(), true);
                 }
             }
         }
     }
 
-    for (id, node) in nodes {
-        if node.kind == PrimitiveKind::Action {
-            if !action_inputs.get(id).copied().unwrap_or(false) {
-                return Err(ValidationError::ActionNotGated(id.clone()));
-            }
+    for node in sorted_nodes(nodes) {
+        if node.kind == PrimitiveKind::Action
+            && !action_inputs
+                .get(&node.runtime_id)
+                .copied()
+                .unwrap_or(false)
+        {
+            diagnostics.push(Diagnostic::node(
+                ValidationError::ActionNotGated(node.runtime_id.clone()),
+                &node.runtime_id,
+            ));
         }
     }
+}
 
-    Ok(())
+fn sorted_nodes(nodes: &HashMap<String, ValidatedNode>) -> Vec<&ValidatedNode> {
+    let mut sorted: Vec<&ValidatedNode> = nodes.values().collect();
+    sorted.sort_by(|a, b| a.runtime_id.cmp(&b.runtime_id));
+    sorted
 }
 
 fn wiring_allowed(from: &PrimitiveKind, to: &PrimitiveKind) -> bool {
-    match (from, to) {
-        (PrimitiveKind::Source, PrimitiveKind::Compute) => true,
-
-        (PrimitiveKind::Compute, PrimitiveKind::Compute) => true,
-        (PrimitiveKind::Compute, PrimitiveKind::Trigger) => true,
-
-        (PrimitiveKind::Trigger, PrimitiveKind::Trigger) => true,
-        (PrimitiveKind::Trigger, PrimitiveKind::Action) => true,
-
-        _ => false,
-    }
+    matches!(
+        (from, to),
+        (PrimitiveKind::Source, PrimitiveKind::Compute)
+            | (PrimitiveKind::Compute, PrimitiveKind::Compute)
+            | (PrimitiveKind::Compute, PrimitiveKind::Trigger)
+            | (PrimitiveKind::Trigger, PrimitiveKind::Trigger)
+            | (PrimitiveKind::Trigger, PrimitiveKind::Action)
+    )
 }
@@ -19,6 +19,7 @@ pub fn boolean_source_manifest() -> SourcePrimitiveManifest {
             value_type: ParameterValue::Bool(false).value_type(),
             default: Some(ParameterValue::Bool(false)),
             bounds: None,
+            variants: Vec::new(),
         }],
         execution: ExecutionSpec {
             deterministic: true,
@@ -26,5 +27,6 @@ pub fn boolean_source_manifest() -> SourcePrimitiveManifest {
         },
         state: StateSpec { allowed: false },
         side_effects: false,
+        environment_key_parameter: None,
     }
 }
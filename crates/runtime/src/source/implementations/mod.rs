@@ -1,5 +1,15 @@
 pub mod boolean;
 pub mod number;
+#[cfg(feature = "serde")]
+pub mod payload_number;
+pub mod state_read_bool;
+pub mod state_read_number;
+pub mod string;
 
 pub use boolean::{boolean_source_manifest, BooleanSource};
 pub use number::{number_source_manifest, NumberSource};
+#[cfg(feature = "serde")]
+pub use payload_number::{payload_number_source_manifest, PayloadNumberSource};
+pub use state_read_bool::{state_read_bool_source_manifest, StateReadBoolSource};
+pub use state_read_number::{state_read_number_source_manifest, StateReadNumberSource};
+pub use string::{string_source_manifest, StringSource};
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::source::{
+    FallibleSourcePrimitive, ParameterValue, SourceContext, SourceError, SourcePrimitiveManifest,
+};
+
+use super::manifest::payload_number_source_manifest;
+
+/// Reads a number from the JSON payload of the event being processed. The output
+/// depends only on the payload bytes and `path`, so replaying a captured event
+/// reproduces it exactly.
+pub struct PayloadNumberSource {
+    manifest: SourcePrimitiveManifest,
+}
+
+impl PayloadNumberSource {
+    pub fn new() -> Self {
+        Self {
+            manifest: payload_number_source_manifest(),
+        }
+    }
+}
+
+impl Default for PayloadNumberSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleSourcePrimitive for PayloadNumberSource {
+    fn manifest(&self) -> &SourcePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_produce(
+        &self,
+        parameters: &HashMap<String, ParameterValue>,
+        ctx: &SourceContext,
+    ) -> Result<HashMap<String, Value>, SourceError> {
+        let path = match parameters.get("path") {
+            Some(ParameterValue::String(path)) => path,
+            _ => {
+                return Err(SourceError::InvalidParameter {
+                    name: "path".to_string(),
+                    reason: "expected a JSON pointer string".to_string(),
+                })
+            }
+        };
+
+        let payload: serde_json::Value = serde_json::from_slice(ctx.payload)
+            .map_err(|e| SourceError::Unavailable(format!("payload is not JSON: {}", e)))?;
+        let value = payload
+            .pointer(path)
+            .ok_or_else(|| SourceError::Unavailable(format!("payload has no field '{}'", path)))?
+            .as_f64()
+            .ok_or_else(|| {
+                SourceError::Unavailable(format!("payload field '{}' is not a number", path))
+            })?;
+
+        Ok(HashMap::from([("value".to_string(), Value::Number(value))]))
+    }
+}
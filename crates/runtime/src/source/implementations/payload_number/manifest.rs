@@ -0,0 +1,33 @@
+use crate::common::ValueType;
+use crate::source::{
+    Cadence, ExecutionSpec, OutputSpec, ParameterSpec, ParameterType, SourceKind,
+    SourcePrimitiveManifest, StateSpec,
+};
+
+pub fn payload_number_source_manifest() -> SourcePrimitiveManifest {
+    SourcePrimitiveManifest {
+        id: "payload_number".to_string(),
+        version: "0.1.0".to_string(),
+        kind: SourceKind::Source,
+        inputs: vec![],
+        outputs: vec![OutputSpec {
+            name: "value".to_string(),
+            value_type: ValueType::Number,
+        }],
+        parameters: vec![ParameterSpec {
+            name: "path".to_string(),
+            value_type: ParameterType::String,
+            default: None,
+            // JSON Pointer (RFC 6901): "" is the whole payload, "/a/0" is a[0].
+            bounds: Some("matches /^(/.*)?$/".to_string()),
+            variants: Vec::new(),
+        }],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec { allowed: false },
+        side_effects: false,
+        environment_key_parameter: None,
+    }
+}
@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::payload_number_source_manifest;
+pub use r#impl::PayloadNumberSource;
@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::state_read_bool_source_manifest;
+pub use r#impl::StateReadBoolSource;
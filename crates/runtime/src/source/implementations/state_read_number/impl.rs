@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use crate::common::Value;
+use crate::source::{
+    FallibleSourcePrimitive, ParameterValue, SourceContext, SourceError, SourcePrimitiveManifest,
+};
+
+use super::manifest::state_read_number_source_manifest;
+
+/// Reads a number from the environment state the pass started with, or `default`
+/// when `key` has never been written. The host captures that state with the event,
+/// so replay reads the same value.
+pub struct StateReadNumberSource {
+    manifest: SourcePrimitiveManifest,
+}
+
+impl StateReadNumberSource {
+    pub fn new() -> Self {
+        Self {
+            manifest: state_read_number_source_manifest(),
+        }
+    }
+}
+
+impl Default for StateReadNumberSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleSourcePrimitive for StateReadNumberSource {
+    fn manifest(&self) -> &SourcePrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_produce(
+        &self,
+        parameters: &HashMap<String, ParameterValue>,
+        ctx: &SourceContext,
+    ) -> Result<HashMap<String, Value>, SourceError> {
+        let key = match parameters.get("key") {
+            Some(ParameterValue::String(key)) => key,
+            _ => {
+                return Err(SourceError::InvalidParameter {
+                    name: "key".to_string(),
+                    reason: "expected a string".to_string(),
+                })
+            }
+        };
+        let value = match (ctx.environment.get(key), parameters.get("default")) {
+            (Some(Value::Number(value)), _) => *value,
+            (Some(_), _) => {
+                return Err(SourceError::Unavailable(format!(
+                    "environment key '{}' does not hold a number",
+                    key
+                )))
+            }
+            (None, Some(ParameterValue::Number(default))) => *default,
+            (None, _) => {
+                return Err(SourceError::InvalidParameter {
+                    name: "default".to_string(),
+                    reason: "expected a number".to_string(),
+                })
+            }
+        };
+
+        Ok(HashMap::from([("value".to_string(), Value::Number(value))]))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::common::ValueType;
+use crate::source::{
+    Cadence, ExecutionSpec, OutputSpec, ParameterSpec, ParameterType, ParameterValue, SourceKind,
+    SourcePrimitiveManifest, StateSpec,
+};
+
+pub fn state_read_number_source_manifest() -> SourcePrimitiveManifest {
+    SourcePrimitiveManifest {
+        id: "state_read_number".to_string(),
+        version: "0.1.0".to_string(),
+        kind: SourceKind::Source,
+        inputs: vec![],
+        outputs: vec![OutputSpec {
+            name: "value".to_string(),
+            value_type: ValueType::Number,
+        }],
+        parameters: vec![
+            ParameterSpec {
+                name: "key".to_string(),
+                value_type: ParameterType::String,
+                default: None,
+                bounds: Some("len[1, )".to_string()),
+                variants: Vec::new(),
+            },
+            ParameterSpec {
+                name: "default".to_string(),
+                value_type: ParameterValue::Number(0.0).value_type(),
+                default: Some(ParameterValue::Number(0.0)),
+                bounds: None,
+                variants: Vec::new(),
+            },
+        ],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec { allowed: false },
+        side_effects: false,
+        environment_key_parameter: Some("key".to_string()),
+    }
+}
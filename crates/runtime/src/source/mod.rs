@@ -1,5 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use crate::common::parameter::ParameterDeclarationError;
 use crate::common::{Value, ValueType};
 
 pub mod graph;
@@ -11,36 +12,6 @@ pub enum SourceKind {
     Source,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum ParameterType {
-    Int,
-    Number,
-    Bool,
-    String,
-    Enum,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum ParameterValue {
-    Int(i64),
-    Number(f64),
-    Bool(bool),
-    String(String),
-    Enum(String),
-}
-
-impl ParameterValue {
-    pub fn value_type(&self) -> ParameterType {
-        match self {
-            ParameterValue::Int(_) => ParameterType::Int,
-            ParameterValue::Number(_) => ParameterType::Number,
-            ParameterValue::Bool(_) => ParameterType::Bool,
-            ParameterValue::String(_) => ParameterType::String,
-            ParameterValue::Enum(_) => ParameterType::Enum,
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq)]
 pub enum Cadence {
     Continuous,
@@ -59,14 +30,6 @@ pub struct OutputSpec {
     pub value_type: ValueType,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct ParameterSpec {
-    pub name: String,
-    pub value_type: ParameterType,
-    pub default: Option<ParameterValue>,
-    pub bounds: Option<String>,
-}
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExecutionSpec {
     pub deterministic: bool,
@@ -89,6 +52,30 @@ pub struct SourcePrimitiveManifest {
     pub execution: ExecutionSpec,
     pub state: StateSpec,
     pub side_effects: bool,
+    /// Names the String parameter holding the environment key this source reads. The
+    /// runtime shows the source only that key, and hosts capture it with each event.
+    pub environment_key_parameter: Option<String>,
+}
+
+impl SourcePrimitiveManifest {
+    /// The environment key a node with `parameters` reads, falling back to the
+    /// parameter's declared default.
+    pub fn environment_key<'a>(
+        &'a self,
+        parameters: &'a HashMap<String, ParameterValue>,
+    ) -> Option<&'a str> {
+        let name = self.environment_key_parameter.as_ref()?;
+        let value = parameters.get(name).or_else(|| {
+            self.parameters
+                .iter()
+                .find(|p| p.name == *name)
+                .and_then(|p| p.default.as_ref())
+        });
+        match value {
+            Some(ParameterValue::String(key)) => Some(key),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -108,6 +95,10 @@ pub enum SourceValidationError {
         expected: ParameterType,
         got: ParameterType,
     },
+    InvalidParameterDeclaration {
+        parameter: String,
+        error: ParameterDeclarationError,
+    },
     UndeclaredParameter {
         node: String,
         parameter: String,
@@ -132,6 +123,8 @@ pub enum SourceValidationError {
         output: String,
     },
     OutputsRequired,
+    /// `environment_key_parameter` does not name a declared String parameter.
+    InvalidEnvironmentKeyParameter(String),
 }
 
 pub trait SourcePrimitive {
@@ -140,8 +133,69 @@ pub trait SourcePrimitive {
     fn produce(&self, parameters: &HashMap<String, ParameterValue>) -> HashMap<String, Value>;
 }
 
+/// What a source may read about the external event being processed.
+#[derive(Debug, Clone, Copy)]
+pub struct SourceContext<'a> {
+    /// Raw payload of the event; empty when the pass was not started by one.
+    pub payload: &'a [u8],
+    /// Environment state as of the start of the pass, limited to the key the manifest's
+    /// `environment_key_parameter` selects; empty for sources that declare none.
+    pub environment: &'a BTreeMap<String, Value>,
+}
+
+static EMPTY_ENVIRONMENT: BTreeMap<String, Value> = BTreeMap::new();
+
+impl Default for SourceContext<'_> {
+    fn default() -> Self {
+        Self {
+            payload: &[],
+            environment: &EMPTY_ENVIRONMENT,
+        }
+    }
+}
+
+/// Failure reported by a source primitive in place of a panic.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SourceError {
+    InvalidParameter { name: String, reason: String },
+    Unavailable(String),
+}
+
+/// Result-returning form of `SourcePrimitive`. This is what the registry stores
+/// and the runtime calls; every `SourcePrimitive` gets it through the blanket impl.
+/// Sources that read the event payload implement it directly.
+pub trait FallibleSourcePrimitive {
+    fn manifest(&self) -> &SourcePrimitiveManifest;
+
+    fn try_produce(
+        &self,
+        parameters: &HashMap<String, ParameterValue>,
+        ctx: &SourceContext,
+    ) -> Result<HashMap<String, Value>, SourceError>;
+}
+
+impl<T: SourcePrimitive> FallibleSourcePrimitive for T {
+    fn manifest(&self) -> &SourcePrimitiveManifest {
+        SourcePrimitive::manifest(self)
+    }
+
+    fn try_produce(
+        &self,
+        parameters: &HashMap<String, ParameterValue>,
+        _ctx: &SourceContext,
+    ) -> Result<HashMap<String, Value>, SourceError> {
+        Ok(self.produce(parameters))
+    }
+}
+
+pub use crate::common::parameter::{ParameterSpec, ParameterType, ParameterValue};
 pub use graph::{NodeOutputRef, SourceGraph, SourceNode};
-pub use implementations::{boolean, number, BooleanSource, NumberSource};
+pub use implementations::{
+    boolean, number, state_read_bool, state_read_number, string, BooleanSource, NumberSource,
+    StateReadBoolSource, StateReadNumberSource, StringSource,
+};
+#[cfg(feature = "serde")]
+pub use implementations::{payload_number, PayloadNumberSource};
 pub use registry::SourceRegistry;
 
 #[cfg(test)]
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 
-use super::{Cadence, SourceKind, SourcePrimitive, SourcePrimitiveManifest, SourceValidationError};
+use super::{
+    Cadence, FallibleSourcePrimitive, ParameterSpec, ParameterType, SourceKind,
+    SourcePrimitiveManifest, SourceValidationError,
+};
 
 pub struct SourceRegistry {
-    primitives: HashMap<String, Box<dyn SourcePrimitive>>,
+    /// Keyed by (id, version) so several versions of a primitive can coexist.
+    primitives: HashMap<(String, String), Box<dyn FallibleSourcePrimitive>>,
 }
 
 impl SourceRegistry {
@@ -47,27 +51,58 @@ impl SourceRegistry {
             return Err(SourceValidationError::OutputsRequired);
         }
 
+        Self::validate_parameters(&manifest.parameters)?;
+
+        if let Some(name) = &manifest.environment_key_parameter {
+            let declared = manifest
+                .parameters
+                .iter()
+                .any(|p| p.name == *name && p.value_type == ParameterType::String);
+            if !declared {
+                return Err(SourceValidationError::InvalidEnvironmentKeyParameter(
+                    name.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Each declaration must parse and pass `common::parameter::check_declaration`.
+    fn validate_parameters(parameters: &[ParameterSpec]) -> Result<(), SourceValidationError> {
+        for param in parameters {
+            param.parsed_bounds().map_err(|error| {
+                SourceValidationError::InvalidParameterDeclaration {
+                    parameter: param.name.clone(),
+                    error,
+                }
+            })?;
+        }
         Ok(())
     }
 
     pub fn register(
         &mut self,
-        primitive: Box<dyn SourcePrimitive>,
+        primitive: Box<dyn FallibleSourcePrimitive>,
     ) -> Result<(), SourceValidationError> {
         let manifest = primitive.manifest();
 
         Self::validate_manifest(manifest)?;
 
-        if self.primitives.contains_key(&manifest.id) {
+        let key = (manifest.id.clone(), manifest.version.clone());
+        if self.primitives.contains_key(&key) {
             return Err(SourceValidationError::DuplicateId(manifest.id.clone()));
         }
 
-        self.primitives.insert(manifest.id.clone(), primitive);
+        self.primitives.insert(key, primitive);
         Ok(())
     }
 
-    pub fn get(&self, id: &str) -> Option<&Box<dyn SourcePrimitive>> {
-        self.primitives.get(id)
+    /// Exact lookup; there is no fallback to another version of the same id.
+    pub fn get(&self, id: &str, version: &str) -> Option<&dyn FallibleSourcePrimitive> {
+        self.primitives
+            .get(&(id.to_string(), version.to_string()))
+            .map(|p| p.as_ref())
     }
 }
 
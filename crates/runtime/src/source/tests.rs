@@ -1,7 +1,14 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use crate::common::bounds::BoundsError;
+use crate::common::parameter::ParameterDeclarationError;
 use crate::common::Value;
-use crate::source::{BooleanSource, NumberSource, SourcePrimitive};
+use crate::source::implementations::{number_source_manifest, state_read_number_source_manifest};
+use crate::source::{
+    BooleanSource, FallibleSourcePrimitive, NumberSource, ParameterSpec, ParameterType,
+    ParameterValue, SourceContext, SourceError, SourcePrimitive, SourceRegistry,
+    SourceValidationError, StateReadBoolSource, StateReadNumberSource, StringSource,
+};
 
 fn expect_panic<F: FnOnce() -> R + std::panic::UnwindSafe, R>(f: F) {
     assert!(std::panic::catch_unwind(f).is_err());
@@ -34,3 +41,174 @@ fn boolean_source_requires_parameter() {
         source.produce(&HashMap::new());
     });
 }
+
+#[test]
+fn string_source_emits_its_parameter() {
+    let source = StringSource::new();
+    let outputs = source.produce(&HashMap::from([(
+        "value".to_string(),
+        ParameterValue::String("EURUSD".to_string()),
+    )]));
+    assert_eq!(
+        outputs.get("value"),
+        Some(&Value::String("EURUSD".to_string()))
+    );
+
+    expect_panic(|| {
+        source.produce(&HashMap::new());
+    });
+}
+
+#[test]
+fn registration_checks_declared_parameter_bounds() {
+    let mut manifest = number_source_manifest();
+    manifest.parameters[0].bounds = Some("[-1, 1]".to_string());
+    assert_eq!(SourceRegistry::validate_manifest(&manifest), Ok(()));
+
+    manifest.parameters[0].bounds = Some("(0, )".to_string());
+    assert_eq!(
+        SourceRegistry::validate_manifest(&manifest),
+        Err(SourceValidationError::InvalidParameterDeclaration {
+            parameter: "value".to_string(),
+            error: ParameterDeclarationError::DefaultOutOfBounds,
+        })
+    );
+
+    manifest.parameters[0].bounds = Some("len[1, 8]".to_string());
+    assert_eq!(
+        SourceRegistry::validate_manifest(&manifest),
+        Err(SourceValidationError::InvalidParameterDeclaration {
+            parameter: "value".to_string(),
+            error: ParameterDeclarationError::InvalidBounds(BoundsError::NotApplicable),
+        })
+    );
+}
+
+#[test]
+fn registration_checks_the_environment_key_parameter() {
+    let mut manifest = state_read_number_source_manifest();
+    assert_eq!(SourceRegistry::validate_manifest(&manifest), Ok(()));
+
+    // `default` is a Number parameter, so it cannot hold a key.
+    for name in ["missing", "default"] {
+        manifest.environment_key_parameter = Some(name.to_string());
+        assert_eq!(
+            SourceRegistry::validate_manifest(&manifest),
+            Err(SourceValidationError::InvalidEnvironmentKeyParameter(
+                name.to_string()
+            ))
+        );
+    }
+}
+
+#[test]
+fn registration_checks_enum_variants() {
+    let mut manifest = number_source_manifest();
+    manifest.parameters[0].variants = vec!["one".to_string()];
+    assert_eq!(
+        SourceRegistry::validate_manifest(&manifest),
+        Err(SourceValidationError::InvalidParameterDeclaration {
+            parameter: "value".to_string(),
+            error: ParameterDeclarationError::VariantsOnNonEnum,
+        })
+    );
+
+    manifest.parameters[0] = ParameterSpec {
+        name: "side".to_string(),
+        value_type: ParameterType::Enum,
+        default: Some(ParameterValue::Enum("hold".to_string())),
+        bounds: None,
+        variants: Vec::new(),
+    };
+    assert_eq!(
+        SourceRegistry::validate_manifest(&manifest),
+        Err(SourceValidationError::InvalidParameterDeclaration {
+            parameter: "side".to_string(),
+            error: ParameterDeclarationError::EnumVariantsRequired,
+        })
+    );
+
+    manifest.parameters[0].variants = vec!["buy".to_string(), "sell".to_string()];
+    assert_eq!(
+        SourceRegistry::validate_manifest(&manifest),
+        Err(SourceValidationError::InvalidParameterDeclaration {
+            parameter: "side".to_string(),
+            error: ParameterDeclarationError::UnknownDefaultVariant("hold".to_string()),
+        })
+    );
+
+    manifest.parameters[0].default = Some(ParameterValue::Enum("sell".to_string()));
+    assert_eq!(SourceRegistry::validate_manifest(&manifest), Ok(()));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn payload_number_reads_a_json_pointer_from_the_event_payload() {
+    let source = crate::source::PayloadNumberSource::new();
+    let parameters = |path: &str| {
+        HashMap::from([("path".to_string(), ParameterValue::String(path.to_string()))])
+    };
+    let ctx = SourceContext {
+        payload: br#"{"quote": {"bid": 101.5, "venue": "x"}, "fills": [3]}"#,
+        ..SourceContext::default()
+    };
+
+    let outputs = source.try_produce(&parameters("/quote/bid"), &ctx).unwrap();
+    assert_eq!(outputs.get("value"), Some(&Value::Number(101.5)));
+    let outputs = source.try_produce(&parameters("/fills/0"), &ctx).unwrap();
+    assert_eq!(outputs.get("value"), Some(&Value::Number(3.0)));
+
+    for path in ["/quote/ask", "/quote/venue"] {
+        assert!(matches!(
+            source.try_produce(&parameters(path), &ctx),
+            Err(SourceError::Unavailable(_))
+        ));
+    }
+    assert!(matches!(
+        source.try_produce(&parameters("/quote/bid"), &SourceContext::default()),
+        Err(SourceError::Unavailable(_))
+    ));
+}
+
+#[test]
+fn state_read_sources_read_the_environment_or_fall_back_to_default() {
+    let environment = BTreeMap::from([
+        ("count".to_string(), Value::Number(2.0)),
+        ("armed".to_string(), Value::Bool(true)),
+    ]);
+    let ctx = SourceContext {
+        environment: &environment,
+        ..SourceContext::default()
+    };
+    let parameters = |key: &str, default: ParameterValue| {
+        HashMap::from([
+            ("key".to_string(), ParameterValue::String(key.to_string())),
+            ("default".to_string(), default),
+        ])
+    };
+
+    let number = StateReadNumberSource::new();
+    let read = |key: &str, ctx: &SourceContext| {
+        number.try_produce(&parameters(key, ParameterValue::Number(-1.0)), ctx)
+    };
+    assert_eq!(read("count", &ctx).unwrap()["value"], Value::Number(2.0));
+    assert_eq!(read("missing", &ctx).unwrap()["value"], Value::Number(-1.0));
+    assert_eq!(
+        read("count", &SourceContext::default()).unwrap()["value"],
+        Value::Number(-1.0)
+    );
+    assert!(matches!(
+        read("armed", &ctx),
+        Err(SourceError::Unavailable(_))
+    ));
+
+    let flag = StateReadBoolSource::new();
+    let outputs = flag
+        .try_produce(&parameters("armed", ParameterValue::Bool(false)), &ctx)
+        .unwrap();
+    assert_eq!(outputs["value"], Value::Bool(true));
+    assert!(matches!(
+        flag.try_produce(&parameters("count", ParameterValue::Bool(false)), &ctx),
+        Err(SourceError::Unavailable(_))
+    ));
+}
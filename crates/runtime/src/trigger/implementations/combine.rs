@@ -0,0 +1,47 @@
+//! Input handling shared by `emit_if_all` and `emit_if_any`.
+//!
+//! Both read two optional `Multiple` ports, `conditions` (Bool) and `events` (Event),
+//! and reduce every wired value to whether it holds. A node with neither port wired
+//! fails with `TriggerError::MissingInput` rather than emitting vacuously.
+
+use std::collections::HashMap;
+
+use crate::trigger::{TriggerError, TriggerEvent, TriggerValue};
+
+pub(super) fn levels(inputs: &HashMap<String, TriggerValue>) -> Result<Vec<bool>, TriggerError> {
+    let mut levels = Vec::new();
+    for name in ["conditions", "events"] {
+        let Some(value) = inputs.get(name) else {
+            continue;
+        };
+        let items = value.as_list().unwrap_or(std::slice::from_ref(value));
+        for item in items {
+            let holds = match (name, item) {
+                ("conditions", TriggerValue::Bool(b)) => *b,
+                ("events", TriggerValue::Event(e)) => *e == TriggerEvent::Emitted,
+                _ => {
+                    return Err(TriggerError::InvalidInput {
+                        name: name.to_string(),
+                        reason: format!("unexpected {:?} value", item.value_type()),
+                    })
+                }
+            };
+            levels.push(holds);
+        }
+    }
+    if levels.is_empty() {
+        return Err(TriggerError::MissingInput {
+            name: "conditions".to_string(),
+        });
+    }
+    Ok(levels)
+}
+
+pub(super) fn emit(should_emit: bool) -> HashMap<String, TriggerValue> {
+    let event = if should_emit {
+        TriggerEvent::Emitted
+    } else {
+        TriggerEvent::NotEmitted
+    };
+    HashMap::from([("event".to_string(), TriggerValue::Event(event))])
+}
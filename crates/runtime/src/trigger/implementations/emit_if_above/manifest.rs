@@ -0,0 +1,38 @@
+use crate::trigger::{
+    Cadence, Cardinality, ExecutionSpec, InputSpec, OutputSpec, ParameterSpec, ParameterType,
+    StateSpec, TriggerKind, TriggerPrimitiveManifest, TriggerValueType,
+};
+
+pub fn emit_if_above_manifest() -> TriggerPrimitiveManifest {
+    TriggerPrimitiveManifest {
+        id: "emit_if_above".to_string(),
+        version: "0.1.0".to_string(),
+        kind: TriggerKind::Trigger,
+        inputs: vec![InputSpec {
+            name: "value".to_string(),
+            value_type: TriggerValueType::Number,
+            required: true,
+            cardinality: Cardinality::Single,
+        }],
+        outputs: vec![OutputSpec {
+            name: "event".to_string(),
+            value_type: TriggerValueType::Event,
+        }],
+        parameters: vec![ParameterSpec {
+            name: "threshold".to_string(),
+            value_type: ParameterType::Number,
+            default: None,
+            bounds: None,
+            variants: Vec::new(),
+        }],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            allowed: false,
+            description: None,
+        },
+        side_effects: false,
+    }
+}
@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::emit_if_above_manifest;
+pub use r#impl::EmitIfAbove;
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use crate::trigger::implementations::combine::{emit, levels};
+use crate::trigger::{
+    FallibleTriggerPrimitive, ParameterValue, TriggerError, TriggerPrimitiveManifest, TriggerState,
+    TriggerValue,
+};
+
+use super::manifest::emit_if_all_manifest;
+
+/// Emits when every wired input holds: a `conditions` value is true or an `events`
+/// value is Emitted. At least one of the two ports must be wired.
+pub struct EmitIfAll {
+    manifest: TriggerPrimitiveManifest,
+}
+
+impl EmitIfAll {
+    pub fn new() -> Self {
+        Self {
+            manifest: emit_if_all_manifest(),
+        }
+    }
+}
+
+impl Default for EmitIfAll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleTriggerPrimitive for EmitIfAll {
+    fn manifest(&self) -> &TriggerPrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_evaluate(
+        &self,
+        inputs: &HashMap<String, TriggerValue>,
+        _parameters: &HashMap<String, ParameterValue>,
+        _state: Option<&mut TriggerState>,
+    ) -> Result<HashMap<String, TriggerValue>, TriggerError> {
+        Ok(emit(levels(inputs)?.into_iter().all(|level| level)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trigger::TriggerEvent;
+
+    #[test]
+    fn combines_conditions_and_events() {
+        let trigger = EmitIfAll::new();
+        let evaluate = |conditions: &[bool], events: &[TriggerEvent]| {
+            let mut inputs = HashMap::new();
+            if !conditions.is_empty() {
+                let items = conditions.iter().map(|c| TriggerValue::Bool(*c)).collect();
+                inputs.insert("conditions".to_string(), TriggerValue::List(items));
+            }
+            if !events.is_empty() {
+                let items = events.iter().cloned().map(TriggerValue::Event).collect();
+                inputs.insert("events".to_string(), TriggerValue::List(items));
+            }
+            trigger.try_evaluate(&inputs, &HashMap::new(), None)
+        };
+        assert_eq!(
+            evaluate(&[true, true], &[TriggerEvent::Emitted]).unwrap()["event"],
+            TriggerValue::Event(TriggerEvent::Emitted)
+        );
+        assert_eq!(
+            evaluate(&[true, false], &[]).unwrap()["event"],
+            TriggerValue::Event(TriggerEvent::NotEmitted)
+        );
+        assert_eq!(
+            evaluate(&[true], &[TriggerEvent::NotEmitted]).unwrap()["event"],
+            TriggerValue::Event(TriggerEvent::NotEmitted)
+        );
+        assert_eq!(
+            evaluate(&[], &[TriggerEvent::Emitted, TriggerEvent::Emitted]).unwrap()["event"],
+            TriggerValue::Event(TriggerEvent::Emitted)
+        );
+
+        assert!(matches!(
+            evaluate(&[], &[]),
+            Err(TriggerError::MissingInput { .. })
+        ));
+        let mistyped = HashMap::from([(
+            "events".to_string(),
+            TriggerValue::List(vec![TriggerValue::Bool(true)]),
+        )]);
+        assert!(matches!(
+            trigger.try_evaluate(&mistyped, &HashMap::new(), None),
+            Err(TriggerError::InvalidInput { name, .. }) if name == "events"
+        ));
+    }
+}
@@ -0,0 +1,40 @@
+use crate::trigger::{
+    Cadence, Cardinality, ExecutionSpec, InputSpec, OutputSpec, StateSpec, TriggerKind,
+    TriggerPrimitiveManifest, TriggerValueType,
+};
+
+pub fn emit_if_all_manifest() -> TriggerPrimitiveManifest {
+    TriggerPrimitiveManifest {
+        id: "emit_if_all".to_string(),
+        version: "0.1.0".to_string(),
+        kind: TriggerKind::Trigger,
+        inputs: vec![
+            InputSpec {
+                name: "conditions".to_string(),
+                value_type: TriggerValueType::Bool,
+                required: false,
+                cardinality: Cardinality::Multiple,
+            },
+            InputSpec {
+                name: "events".to_string(),
+                value_type: TriggerValueType::Event,
+                required: false,
+                cardinality: Cardinality::Multiple,
+            },
+        ],
+        outputs: vec![OutputSpec {
+            name: "event".to_string(),
+            value_type: TriggerValueType::Event,
+        }],
+        parameters: vec![],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            allowed: false,
+            description: None,
+        },
+        side_effects: false,
+    }
+}
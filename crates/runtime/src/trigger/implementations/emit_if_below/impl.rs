@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use crate::trigger::{
+    FallibleTriggerPrimitive, ParameterValue, TriggerError, TriggerEvent, TriggerPrimitiveManifest,
+    TriggerState, TriggerValue,
+};
+
+use super::manifest::emit_if_below_manifest;
+
+/// Emits when `value` is strictly below `threshold`.
+pub struct EmitIfBelow {
+    manifest: TriggerPrimitiveManifest,
+}
+
+impl EmitIfBelow {
+    pub fn new() -> Self {
+        Self {
+            manifest: emit_if_below_manifest(),
+        }
+    }
+}
+
+impl Default for EmitIfBelow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleTriggerPrimitive for EmitIfBelow {
+    fn manifest(&self) -> &TriggerPrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_evaluate(
+        &self,
+        inputs: &HashMap<String, TriggerValue>,
+        parameters: &HashMap<String, ParameterValue>,
+        _state: Option<&mut TriggerState>,
+    ) -> Result<HashMap<String, TriggerValue>, TriggerError> {
+        let number = |name: &str| match inputs.get(name) {
+            Some(value) => value.as_number().ok_or_else(|| TriggerError::InvalidInput {
+                name: name.to_string(),
+                reason: "expected a number".to_string(),
+            }),
+            None => Err(TriggerError::MissingInput {
+                name: name.to_string(),
+            }),
+        };
+        let value = number("value")?;
+        let threshold = match parameters.get("threshold") {
+            Some(ParameterValue::Number(n)) => *n,
+            _ => {
+                return Err(TriggerError::InvalidParameter {
+                    name: "threshold".to_string(),
+                    reason: "missing numeric parameter".to_string(),
+                })
+            }
+        };
+        let should_emit = value < threshold;
+
+        let event = if should_emit {
+            TriggerEvent::Emitted
+        } else {
+            TriggerEvent::NotEmitted
+        };
+
+        Ok(HashMap::from([(
+            "event".to_string(),
+            TriggerValue::Event(event),
+        )]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emits_only_strictly_below_the_threshold() {
+        let trigger = EmitIfBelow::new();
+        let threshold = HashMap::from([("threshold".to_string(), ParameterValue::Number(1.0))]);
+        let evaluate = |value: f64| {
+            trigger
+                .try_evaluate(
+                    &HashMap::from([("value".to_string(), TriggerValue::Number(value))]),
+                    &threshold,
+                    None,
+                )
+                .map(|outputs| outputs["event"].clone())
+        };
+        assert_eq!(
+            evaluate(0.5),
+            Ok(TriggerValue::Event(TriggerEvent::Emitted))
+        );
+        assert_eq!(
+            evaluate(1.0),
+            Ok(TriggerValue::Event(TriggerEvent::NotEmitted))
+        );
+        assert_eq!(
+            evaluate(2.0),
+            Ok(TriggerValue::Event(TriggerEvent::NotEmitted))
+        );
+
+        assert!(matches!(
+            trigger.try_evaluate(
+                &HashMap::from([("value".to_string(), TriggerValue::Number(2.0))]),
+                &HashMap::new(),
+                None,
+            ),
+            Err(TriggerError::InvalidParameter { name, .. }) if name == "threshold"
+        ));
+        assert_eq!(
+            trigger.try_evaluate(&HashMap::new(), &threshold, None),
+            Err(TriggerError::MissingInput {
+                name: "value".to_string()
+            })
+        );
+        assert!(matches!(
+            trigger.try_evaluate(
+                &HashMap::from([("value".to_string(), TriggerValue::Bool(true))]),
+                &threshold,
+                None,
+            ),
+            Err(TriggerError::InvalidInput { name, .. }) if name == "value"
+        ));
+    }
+}
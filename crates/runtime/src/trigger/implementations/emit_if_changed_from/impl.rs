@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::trigger::{
+    FallibleTriggerPrimitive, ParameterValue, TriggerError, TriggerEvent, TriggerPrimitiveManifest,
+    TriggerState, TriggerValue,
+};
+
+use super::manifest::emit_if_changed_from_manifest;
+
+/// Emits when `current` differs from `previous`. The trigger keeps no history; the
+/// previous value is wired in, for instance from `lag` or a `state_read_number` source.
+pub struct EmitIfChangedFrom {
+    manifest: TriggerPrimitiveManifest,
+}
+
+impl EmitIfChangedFrom {
+    pub fn new() -> Self {
+        Self {
+            manifest: emit_if_changed_from_manifest(),
+        }
+    }
+}
+
+impl Default for EmitIfChangedFrom {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleTriggerPrimitive for EmitIfChangedFrom {
+    fn manifest(&self) -> &TriggerPrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_evaluate(
+        &self,
+        inputs: &HashMap<String, TriggerValue>,
+        _parameters: &HashMap<String, ParameterValue>,
+        _state: Option<&mut TriggerState>,
+    ) -> Result<HashMap<String, TriggerValue>, TriggerError> {
+        let number = |name: &str| match inputs.get(name) {
+            Some(value) => value.as_number().ok_or_else(|| TriggerError::InvalidInput {
+                name: name.to_string(),
+                reason: "expected a number".to_string(),
+            }),
+            None => Err(TriggerError::MissingInput {
+                name: name.to_string(),
+            }),
+        };
+        let current = number("current")?;
+        let previous = number("previous")?;
+        let should_emit = current != previous;
+
+        let event = if should_emit {
+            TriggerEvent::Emitted
+        } else {
+            TriggerEvent::NotEmitted
+        };
+
+        Ok(HashMap::from([(
+            "event".to_string(),
+            TriggerValue::Event(event),
+        )]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emits_only_when_the_value_changed() {
+        let trigger = EmitIfChangedFrom::new();
+        let evaluate = |current: f64, previous: f64| {
+            trigger
+                .try_evaluate(
+                    &HashMap::from([
+                        ("current".to_string(), TriggerValue::Number(current)),
+                        ("previous".to_string(), TriggerValue::Number(previous)),
+                    ]),
+                    &HashMap::new(),
+                    None,
+                )
+                .map(|outputs| outputs["event"].clone())
+        };
+        assert_eq!(
+            evaluate(2.0, 1.0),
+            Ok(TriggerValue::Event(TriggerEvent::Emitted))
+        );
+        assert_eq!(
+            evaluate(1.0, 1.0),
+            Ok(TriggerValue::Event(TriggerEvent::NotEmitted))
+        );
+
+        assert_eq!(
+            trigger.try_evaluate(
+                &HashMap::from([("current".to_string(), TriggerValue::Number(1.0))]),
+                &HashMap::new(),
+                None,
+            ),
+            Err(TriggerError::MissingInput {
+                name: "previous".to_string()
+            })
+        );
+        assert!(matches!(
+            trigger.try_evaluate(
+                &HashMap::from([
+                    ("current".to_string(), TriggerValue::Number(1.0)),
+                    ("previous".to_string(), TriggerValue::Bool(false)),
+                ]),
+                &HashMap::new(),
+                None,
+            ),
+            Err(TriggerError::InvalidInput { name, .. }) if name == "previous"
+        ));
+    }
+}
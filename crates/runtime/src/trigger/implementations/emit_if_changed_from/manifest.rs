@@ -0,0 +1,40 @@
+use crate::trigger::{
+    Cadence, Cardinality, ExecutionSpec, InputSpec, OutputSpec, StateSpec, TriggerKind,
+    TriggerPrimitiveManifest, TriggerValueType,
+};
+
+pub fn emit_if_changed_from_manifest() -> TriggerPrimitiveManifest {
+    TriggerPrimitiveManifest {
+        id: "emit_if_changed_from".to_string(),
+        version: "0.1.0".to_string(),
+        kind: TriggerKind::Trigger,
+        inputs: vec![
+            InputSpec {
+                name: "current".to_string(),
+                value_type: TriggerValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+            InputSpec {
+                name: "previous".to_string(),
+                value_type: TriggerValueType::Number,
+                required: true,
+                cardinality: Cardinality::Single,
+            },
+        ],
+        outputs: vec![OutputSpec {
+            name: "event".to_string(),
+            value_type: TriggerValueType::Event,
+        }],
+        parameters: vec![],
+        execution: ExecutionSpec {
+            deterministic: true,
+            cadence: Cadence::Continuous,
+        },
+        state: StateSpec {
+            allowed: false,
+            description: None,
+        },
+        side_effects: false,
+    }
+}
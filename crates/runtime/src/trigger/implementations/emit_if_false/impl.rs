@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use crate::trigger::{
+    FallibleTriggerPrimitive, ParameterValue, TriggerError, TriggerEvent, TriggerPrimitiveManifest,
+    TriggerState, TriggerValue,
+};
+
+use super::manifest::emit_if_false_manifest;
+
+pub struct EmitIfFalse {
+    manifest: TriggerPrimitiveManifest,
+}
+
+impl EmitIfFalse {
+    pub fn new() -> Self {
+        Self {
+            manifest: emit_if_false_manifest(),
+        }
+    }
+}
+
+impl Default for EmitIfFalse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FallibleTriggerPrimitive for EmitIfFalse {
+    fn manifest(&self) -> &TriggerPrimitiveManifest {
+        &self.manifest
+    }
+
+    fn try_evaluate(
+        &self,
+        inputs: &HashMap<String, TriggerValue>,
+        _parameters: &HashMap<String, ParameterValue>,
+        _state: Option<&mut TriggerState>,
+    ) -> Result<HashMap<String, TriggerValue>, TriggerError> {
+        let input = match inputs.get("input") {
+            Some(value) => value.as_bool().ok_or_else(|| TriggerError::InvalidInput {
+                name: "input".to_string(),
+                reason: "expected a bool".to_string(),
+            })?,
+            None => {
+                return Err(TriggerError::MissingInput {
+                    name: "input".to_string(),
+                })
+            }
+        };
+        let should_emit = !input;
+
+        let event = if should_emit {
+            TriggerEvent::Emitted
+        } else {
+            TriggerEvent::NotEmitted
+        };
+
+        Ok(HashMap::from([(
+            "event".to_string(),
+            TriggerValue::Event(event),
+        )]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emits_only_on_false() {
+        let trigger = EmitIfFalse::new();
+        let evaluate = |input: TriggerValue| {
+            trigger
+                .try_evaluate(
+                    &HashMap::from([("input".to_string(), input)]),
+                    &HashMap::new(),
+                    None,
+                )
+                .map(|outputs| outputs["event"].clone())
+        };
+        assert_eq!(
+            evaluate(TriggerValue::Bool(false)),
+            Ok(TriggerValue::Event(TriggerEvent::Emitted))
+        );
+        assert_eq!(
+            evaluate(TriggerValue::Bool(true)),
+            Ok(TriggerValue::Event(TriggerEvent::NotEmitted))
+        );
+
+        assert!(matches!(
+            evaluate(TriggerValue::Number(0.0)),
+            Err(TriggerError::InvalidInput { name, .. }) if name == "input"
+        ));
+        assert_eq!(
+            trigger.try_evaluate(&HashMap::new(), &HashMap::new(), None),
+            Err(TriggerError::MissingInput {
+                name: "input".to_string()
+            })
+        );
+    }
+}
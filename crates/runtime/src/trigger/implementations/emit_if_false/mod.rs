@@ -0,0 +1,5 @@
+pub mod r#impl;
+pub mod manifest;
+
+pub use manifest::emit_if_false_manifest;
+pub use r#impl::EmitIfFalse;
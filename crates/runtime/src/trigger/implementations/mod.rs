@@ -1,3 +1,16 @@
+mod combine;
+pub mod emit_if_above;
+pub mod emit_if_all;
+pub mod emit_if_any;
+pub mod emit_if_below;
+pub mod emit_if_changed_from;
+pub mod emit_if_false;
 pub mod emit_if_true;
 
+pub use emit_if_above::EmitIfAbove;
+pub use emit_if_all::EmitIfAll;
+pub use emit_if_any::EmitIfAny;
+pub use emit_if_below::EmitIfBelow;
+pub use emit_if_changed_from::EmitIfChangedFrom;
+pub use emit_if_false::EmitIfFalse;
 pub use emit_if_true::EmitIfTrue;
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::common::parameter::ParameterDeclarationError;
+
 pub mod graph;
 pub mod implementations;
 pub mod registry;
@@ -18,6 +20,7 @@ pub enum TriggerValueType {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TriggerEvent {
     Emitted,
     NotEmitted,
@@ -29,6 +32,8 @@ pub enum TriggerValue {
     Series(Vec<f64>),
     Bool(bool),
     Event(TriggerEvent),
+    /// Values of a `Cardinality::Multiple` input, in edge order.
+    List(Vec<TriggerValue>),
 }
 
 impl TriggerValue {
@@ -38,6 +43,10 @@ impl TriggerValue {
             TriggerValue::Series(_) => TriggerValueType::Series,
             TriggerValue::Bool(_) => TriggerValueType::Bool,
             TriggerValue::Event(_) => TriggerValueType::Event,
+            // A list is typed by its items; the runtime never delivers an empty one.
+            TriggerValue::List(items) => items
+                .first()
+                .map_or(TriggerValueType::Series, TriggerValue::value_type),
         }
     }
 
@@ -61,34 +70,11 @@ impl TriggerValue {
             _ => None,
         }
     }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum ParameterType {
-    Int,
-    Number,
-    Bool,
-    String,
-    Enum,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub enum ParameterValue {
-    Int(i64),
-    Number(f64),
-    Bool(bool),
-    String(String),
-    Enum(String),
-}
 
-impl ParameterValue {
-    pub fn value_type(&self) -> ParameterType {
+    pub fn as_list(&self) -> Option<&[TriggerValue]> {
         match self {
-            ParameterValue::Int(_) => ParameterType::Int,
-            ParameterValue::Number(_) => ParameterType::Number,
-            ParameterValue::Bool(_) => ParameterType::Bool,
-            ParameterValue::String(_) => ParameterType::String,
-            ParameterValue::Enum(_) => ParameterType::Enum,
+            TriggerValue::List(items) => Some(items),
+            _ => None,
         }
     }
 }
@@ -113,14 +99,6 @@ pub struct OutputSpec {
     pub value_type: TriggerValueType,
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct ParameterSpec {
-    pub name: String,
-    pub value_type: ParameterType,
-    pub default: Option<ParameterValue>,
-    pub bounds: Option<String>,
-}
-
 #[derive(Debug, Clone, PartialEq)]
 pub enum Cadence {
     Continuous,
@@ -198,6 +176,10 @@ pub enum TriggerValidationError {
         expected: ParameterType,
         got: ParameterType,
     },
+    InvalidParameterDeclaration {
+        parameter: String,
+        error: ParameterDeclarationError,
+    },
     UnknownPrimitive(String),
     CycleDetected,
     MissingNode(String),
@@ -222,6 +204,46 @@ pub trait TriggerPrimitive {
     ) -> HashMap<String, TriggerValue>;
 }
 
+/// Failure reported by a trigger primitive in place of a panic.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriggerError {
+    MissingInput { name: String },
+    InvalidInput { name: String, reason: String },
+    InvalidParameter { name: String, reason: String },
+    Failed(String),
+}
+
+/// Result-returning form of `TriggerPrimitive`. This is what the registry stores
+/// and the runtime calls; every `TriggerPrimitive` gets it through the blanket impl.
+pub trait FallibleTriggerPrimitive {
+    fn manifest(&self) -> &TriggerPrimitiveManifest;
+
+    fn try_evaluate(
+        &self,
+        inputs: &HashMap<String, TriggerValue>,
+        parameters: &HashMap<String, ParameterValue>,
+        state: Option<&mut TriggerState>,
+    ) -> Result<HashMap<String, TriggerValue>, TriggerError>;
+}
+
+impl<T: TriggerPrimitive> FallibleTriggerPrimitive for T {
+    fn manifest(&self) -> &TriggerPrimitiveManifest {
+        TriggerPrimitive::manifest(self)
+    }
+
+    fn try_evaluate(
+        &self,
+        inputs: &HashMap<String, TriggerValue>,
+        parameters: &HashMap<String, ParameterValue>,
+        state: Option<&mut TriggerState>,
+    ) -> Result<HashMap<String, TriggerValue>, TriggerError> {
+        Ok(self.evaluate(inputs, parameters, state))
+    }
+}
+
+pub use crate::common::parameter::{ParameterSpec, ParameterType, ParameterValue};
 pub use graph::{InputBinding, NodeOutputRef, TriggerGraph, TriggerNode};
-pub use implementations::emit_if_true::EmitIfTrue;
+pub use implementations::{
+    EmitIfAbove, EmitIfAll, EmitIfAny, EmitIfBelow, EmitIfChangedFrom, EmitIfFalse, EmitIfTrue,
+};
 pub use registry::TriggerRegistry;
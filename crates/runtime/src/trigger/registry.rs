@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 
 use super::{
-    OutputSpec, TriggerKind, TriggerPrimitive, TriggerPrimitiveManifest, TriggerValidationError,
-    TriggerValueType,
+    FallibleTriggerPrimitive, OutputSpec, ParameterSpec, TriggerKind, TriggerPrimitiveManifest,
+    TriggerValidationError, TriggerValueType,
 };
 
 pub struct TriggerRegistry {
-    primitives: HashMap<String, Box<dyn TriggerPrimitive>>,
+    /// Keyed by (id, version) so several versions of a primitive can coexist.
+    primitives: HashMap<(String, String), Box<dyn FallibleTriggerPrimitive>>,
 }
 
 impl TriggerRegistry {
@@ -43,10 +44,24 @@ impl TriggerRegistry {
         }
 
         Self::validate_outputs(&manifest.outputs)?;
+        Self::validate_parameters(&manifest.parameters)?;
 
         Ok(())
     }
 
+    /// Each declaration must parse and pass `common::parameter::check_declaration`.
+    fn validate_parameters(parameters: &[ParameterSpec]) -> Result<(), TriggerValidationError> {
+        for param in parameters {
+            param.parsed_bounds().map_err(|error| {
+                TriggerValidationError::InvalidParameterDeclaration {
+                    parameter: param.name.clone(),
+                    error,
+                }
+            })?;
+        }
+        Ok(())
+    }
+
     fn validate_outputs(outputs: &[OutputSpec]) -> Result<(), TriggerValidationError> {
         if outputs.len() != 1 {
             return Err(TriggerValidationError::UndeclaredOutput {
@@ -69,22 +84,26 @@ impl TriggerRegistry {
 
     pub fn register(
         &mut self,
-        primitive: Box<dyn TriggerPrimitive>,
+        primitive: Box<dyn FallibleTriggerPrimitive>,
     ) -> Result<(), TriggerValidationError> {
         let manifest = primitive.manifest();
 
         Self::validate_manifest(manifest)?;
 
-        if self.primitives.contains_key(&manifest.id) {
+        let key = (manifest.id.clone(), manifest.version.clone());
+        if self.primitives.contains_key(&key) {
             return Err(TriggerValidationError::DuplicateId(manifest.id.clone()));
         }
 
-        self.primitives.insert(manifest.id.clone(), primitive);
+        self.primitives.insert(key, primitive);
         Ok(())
     }
 
-    pub fn get(&self, id: &str) -> Option<&Box<dyn TriggerPrimitive>> {
-        self.primitives.get(id)
+    /// Exact lookup; there is no fallback to another version of the same id.
+    pub fn get(&self, id: &str, version: &str) -> Option<&dyn FallibleTriggerPrimitive> {
+        self.primitives
+            .get(&(id.to_string(), version.to_string()))
+            .map(|p| p.as_ref())
     }
 }
 
@@ -149,4 +168,31 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn stdlib_triggers_pass_validation() {
+        use crate::trigger::implementations::{
+            emit_if_above::emit_if_above_manifest, emit_if_all::emit_if_all_manifest,
+            emit_if_any::emit_if_any_manifest, emit_if_below::emit_if_below_manifest,
+            emit_if_changed_from::emit_if_changed_from_manifest,
+            emit_if_false::emit_if_false_manifest, emit_if_true::emit_if_true_manifest,
+        };
+
+        for manifest in [
+            emit_if_true_manifest(),
+            emit_if_false_manifest(),
+            emit_if_all_manifest(),
+            emit_if_any_manifest(),
+            emit_if_above_manifest(),
+            emit_if_below_manifest(),
+            emit_if_changed_from_manifest(),
+        ] {
+            assert_eq!(
+                TriggerRegistry::validate_manifest(&manifest),
+                Ok(()),
+                "{}",
+                manifest.id
+            );
+        }
+    }
 }
@@ -0,0 +1,578 @@
+//! HTTP backend for the authoring UI.
+//!
+//! Serves the two endpoints `ui-authoring/src/runtime/adapter.ts` calls:
+//!
+//! - `POST /validate` — body: `ExpandedGraph`, response: `ValidationResult`
+//! - `POST /run` — body: `ExpandedGraph`, response: `RuntimeResponse`
+//!
+//! Response shapes mirror `ui-authoring/src/runtime/types.ts`. The request
+//! handling lives here, independent of the transport, so it can be exercised
+//! without opening a socket; `main.rs` only wires it to `tiny_http`.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use ergo_runtime::catalog::{
+    build_core_catalog, core_registries, CorePrimitiveCatalog, CoreRegistries,
+};
+use ergo_runtime::cluster::ExpandedGraph;
+use ergo_runtime::runtime::{
+    execute, validate_all, Diagnostic, ExecError, ExecutionContext, ExecutionReport, Registries,
+    ValidationError,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ValidationErrorKind {
+    UnknownPrimitive,
+    MissingRequiredInput,
+    TypeMismatch,
+    InvalidWiring,
+    UngatedAction,
+    CycleDetected,
+    InvalidParameter,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edge_index: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationErrorDto {
+    pub kind: ValidationErrorKind,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<ErrorLocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationResult {
+    pub success: bool,
+    pub errors: Vec<ValidationErrorDto>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExecutionErrorKind {
+    RuntimePanic,
+    TypeCoercionFailed,
+    MissingInput,
+    ActionFailed,
+    PrimitiveFailed,
+    /// The graph and the registries or context it ran with do not fit together.
+    InvalidConfiguration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionErrorDto {
+    pub kind: ExecutionErrorKind,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ExecutionResult {
+    Ok {
+        success: bool,
+        report: ExecutionReport,
+    },
+    Err {
+        success: bool,
+        error: ExecutionErrorDto,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuntimeResponse {
+    pub validation: ValidationResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execution: Option<ExecutionResult>,
+}
+
+/// Transport-independent HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string(value).expect("response types always serialize"),
+        }
+    }
+
+    fn message(status: u16, message: impl Into<String>) -> Self {
+        Self::json(status, &serde_json::json!({ "message": message.into() }))
+    }
+}
+
+/// Owns the core catalog and registries for the lifetime of the server.
+pub struct Backend {
+    catalog: CorePrimitiveCatalog,
+    registries: CoreRegistries,
+}
+
+impl Backend {
+    pub fn new() -> Result<Self, String> {
+        let registries =
+            core_registries().map_err(|e| format!("core registration failed: {:?}", e))?;
+        Ok(Self {
+            catalog: build_core_catalog(),
+            registries,
+        })
+    }
+
+    /// Route a request. CORS preflight is answered by the transport layer.
+    pub fn handle(&self, method: &str, path: &str, body: &str) -> Response {
+        match (method, path) {
+            ("POST", "/validate") => match parse_graph(body) {
+                Ok(graph) => Response::json(200, &self.validate_graph(&graph)),
+                Err(response) => response,
+            },
+            ("POST", "/run") => match parse_graph(body) {
+                Ok(graph) => Response::json(200, &self.run_graph(&graph)),
+                Err(response) => response,
+            },
+            (_, "/validate") | (_, "/run") => Response::message(405, "method not allowed"),
+            _ => Response::message(404, format!("no route for {}", path)),
+        }
+    }
+
+    pub fn validate_graph(&self, graph: &ExpandedGraph) -> ValidationResult {
+        match validate_all(graph, &self.catalog) {
+            Ok(_) => ValidationResult {
+                success: true,
+                errors: Vec::new(),
+            },
+            Err(diagnostics) => ValidationResult {
+                success: false,
+                errors: diagnostics.iter().map(map_diagnostic).collect(),
+            },
+        }
+    }
+
+    /// Validates first; execution is only attempted for a valid graph.
+    pub fn run_graph(&self, graph: &ExpandedGraph) -> RuntimeResponse {
+        let validated = match validate_all(graph, &self.catalog) {
+            Ok(validated) => validated,
+            Err(diagnostics) => {
+                return RuntimeResponse {
+                    validation: ValidationResult {
+                        success: false,
+                        errors: diagnostics.iter().map(map_diagnostic).collect(),
+                    },
+                    execution: None,
+                }
+            }
+        };
+
+        let registries = Registries {
+            sources: &self.registries.sources,
+            computes: &self.registries.computes,
+            triggers: &self.registries.triggers,
+            actions: &self.registries.actions,
+        };
+        let ctx = ExecutionContext::default();
+
+        // A primitive panic must not take the server down with it.
+        let outcome =
+            panic::catch_unwind(AssertUnwindSafe(|| execute(&validated, &registries, &ctx)));
+
+        let execution = match outcome {
+            Ok(Ok(report)) => ExecutionResult::Ok {
+                success: true,
+                report,
+            },
+            Ok(Err(err)) => ExecutionResult::Err {
+                success: false,
+                error: map_exec_error(&err),
+            },
+            Err(payload) => ExecutionResult::Err {
+                success: false,
+                error: ExecutionErrorDto {
+                    kind: ExecutionErrorKind::RuntimePanic,
+                    message: panic_message(payload.as_ref()),
+                    node_id: None,
+                },
+            },
+        };
+
+        RuntimeResponse {
+            validation: ValidationResult {
+                success: true,
+                errors: Vec::new(),
+            },
+            execution: Some(execution),
+        }
+    }
+}
+
+fn parse_graph(body: &str) -> Result<ExpandedGraph, Response> {
+    serde_json::from_str(body)
+        .map_err(|e| Response::message(400, format!("invalid ExpandedGraph: {}", e)))
+}
+
+/// Every validation error is reported, each located by the diagnostic's node, port and edge.
+pub fn map_diagnostic(diagnostic: &Diagnostic) -> ValidationErrorDto {
+    let (kind, message) = match &diagnostic.error {
+        ValidationError::CycleDetected { nodes } => (
+            ValidationErrorKind::CycleDetected,
+            format!("nodes form a cycle: {}", nodes.join(", ")),
+        ),
+        ValidationError::UnknownNode(node) => (
+            ValidationErrorKind::InvalidWiring,
+            format!("edge references unknown node '{}'", node),
+        ),
+        ValidationError::MissingPrimitive { id, version } => (
+            ValidationErrorKind::UnknownPrimitive,
+            format!("primitive '{}@{}' is not in the catalog", id, version),
+        ),
+        ValidationError::InvalidEdgeKind { from, to } => (
+            ValidationErrorKind::InvalidWiring,
+            format!("{:?} -> {:?} wiring is not allowed", from, to),
+        ),
+        ValidationError::MissingRequiredInput { node, input } => (
+            ValidationErrorKind::MissingRequiredInput,
+            format!(
+                "required input '{}' of node '{}' is not connected",
+                input, node
+            ),
+        ),
+        ValidationError::MissingInputMetadata { node, input } => (
+            ValidationErrorKind::InvalidWiring,
+            format!("node '{}' has no input named '{}'", node, input),
+        ),
+        ValidationError::TypeMismatch {
+            from,
+            output,
+            to,
+            input,
+            expected,
+            got,
+        } => (
+            ValidationErrorKind::TypeMismatch,
+            format!(
+                "{}:{} produces {:?} but {}:{} expects {:?}",
+                from, output, got, to, input, expected
+            ),
+        ),
+        ValidationError::ActionNotGated(node) => (
+            ValidationErrorKind::UngatedAction,
+            format!("action '{}' is not gated by a trigger event", node),
+        ),
+        ValidationError::MissingOutputMetadata { node, output } => (
+            ValidationErrorKind::InvalidWiring,
+            format!("node '{}' has no output named '{}'", node, output),
+        ),
+        ValidationError::ExternalInputNotAllowed { name } => (
+            ValidationErrorKind::InvalidWiring,
+            format!("external input '{}' is not allowed in runtime graphs", name),
+        ),
+        ValidationError::UnknownParameter { node, parameter } => (
+            ValidationErrorKind::InvalidParameter,
+            format!("node '{}' has no parameter named '{}'", node, parameter),
+        ),
+        ValidationError::ParameterTypeMismatch {
+            node,
+            parameter,
+            expected,
+            got,
+        } => (
+            ValidationErrorKind::InvalidParameter,
+            format!(
+                "parameter '{}' of node '{}' expects {:?} but got {:?}",
+                parameter, node, expected, got
+            ),
+        ),
+        ValidationError::MissingRequiredParameter { node, parameter } => (
+            ValidationErrorKind::InvalidParameter,
+            format!(
+                "required parameter '{}' of node '{}' is not set",
+                parameter, node
+            ),
+        ),
+        ValidationError::InvalidParameterBounds {
+            node,
+            parameter,
+            error,
+        } => (
+            ValidationErrorKind::InvalidParameter,
+            format!(
+                "parameter '{}' of node '{}' declares unusable bounds: {:?}",
+                parameter, node, error
+            ),
+        ),
+        ValidationError::ParameterOutOfBounds {
+            node,
+            parameter,
+            bounds,
+        } => (
+            ValidationErrorKind::InvalidParameter,
+            format!(
+                "parameter '{}' of node '{}' is outside its bounds {}",
+                parameter, node, bounds
+            ),
+        ),
+        ValidationError::UnknownEnumVariant {
+            node,
+            parameter,
+            variant,
+        } => (
+            ValidationErrorKind::InvalidParameter,
+            format!(
+                "parameter '{}' of node '{}' has no variant '{}'",
+                parameter, node, variant
+            ),
+        ),
+        ValidationError::UnknownBoundaryNode { output, node } => (
+            ValidationErrorKind::InvalidWiring,
+            format!("graph output '{}' reads unknown node '{}'", output, node),
+        ),
+        ValidationError::UnknownBoundaryPort { output, node, port } => (
+            ValidationErrorKind::InvalidWiring,
+            format!(
+                "graph output '{}' reads '{}' of node '{}', which has no such output",
+                output, port, node
+            ),
+        ),
+        ValidationError::MultipleEdgesIntoSingleInput { node, input } => (
+            ValidationErrorKind::InvalidWiring,
+            format!(
+                "input '{}' of node '{}' accepts a single edge but has several",
+                input, node
+            ),
+        ),
+    };
+
+    let location = ErrorLocation {
+        node_id: diagnostic.node.clone(),
+        port_name: diagnostic.port.clone(),
+        edge_index: diagnostic.edge,
+    };
+
+    ValidationErrorDto {
+        kind,
+        message,
+        location: (location != ErrorLocation::default()).then_some(location),
+    }
+}
+
+pub fn map_exec_error(err: &ExecError) -> ExecutionErrorDto {
+    let (kind, message, node_id) = match err {
+        ExecError::UnknownPrimitive { id, version } => (
+            ExecutionErrorKind::InvalidConfiguration,
+            format!("primitive '{}@{}' is not registered", id, version),
+            None,
+        ),
+        ExecError::TypeConversionFailed { node, port } => (
+            ExecutionErrorKind::TypeCoercionFailed,
+            format!("value on port '{}' could not be converted", port),
+            Some(node.clone()),
+        ),
+        ExecError::ParameterTypeConversionFailed { node, parameter } => (
+            ExecutionErrorKind::TypeCoercionFailed,
+            format!("parameter '{}' could not be converted", parameter),
+            Some(node.clone()),
+        ),
+        ExecError::ActionExecutionFailed(inner) => (
+            ExecutionErrorKind::ActionFailed,
+            format!("action failed: {:?}", inner),
+            None,
+        ),
+        ExecError::SourceFailed { node, error } => (
+            ExecutionErrorKind::PrimitiveFailed,
+            format!("source failed: {:?}", error),
+            Some(node.clone()),
+        ),
+        ExecError::ComputeFailed { node, error } => (
+            ExecutionErrorKind::PrimitiveFailed,
+            format!("compute failed: {:?}", error),
+            Some(node.clone()),
+        ),
+        ExecError::TriggerFailed { node, error } => (
+            ExecutionErrorKind::PrimitiveFailed,
+            format!("trigger failed: {:?}", error),
+            Some(node.clone()),
+        ),
+        ExecError::ActionFailed { node, error } => (
+            ExecutionErrorKind::ActionFailed,
+            format!("action failed: {:?}", error),
+            Some(node.clone()),
+        ),
+        ExecError::PrimitivePanicked {
+            node,
+            impl_id,
+            message,
+        } => (
+            ExecutionErrorKind::RuntimePanic,
+            format!("primitive '{}' panicked: {}", impl_id, message),
+            Some(node.clone()),
+        ),
+        ExecError::UndeclaredState { node } => (
+            ExecutionErrorKind::InvalidConfiguration,
+            format!("state supplied for node '{}', which declares none", node),
+            Some(node.clone()),
+        ),
+        ExecError::NonFiniteValue { node, port } => (
+            ExecutionErrorKind::PrimitiveFailed,
+            format!("node '{}' produced a non-finite value on '{}'", node, port),
+            Some(node.clone()),
+        ),
+        ExecError::MissingOutput { node, output } => (
+            ExecutionErrorKind::MissingInput,
+            format!("node '{}' did not produce output '{}'", node, output),
+            Some(node.clone()),
+        ),
+    };
+
+    ExecutionErrorDto {
+        kind,
+        message,
+        node_id,
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "primitive panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HELLO_WORLD: &str = r#"{
+        "nodes": {
+            "src_a": { "runtime_id": "src_a", "authoring_path": [],
+                "implementation": { "impl_id": "number_source", "version": "0.1.0" },
+                "parameters": { "value": { "type": "Number", "value": 3.0 } } },
+            "src_b": { "runtime_id": "src_b", "authoring_path": [],
+                "implementation": { "impl_id": "number_source", "version": "0.1.0" },
+                "parameters": { "value": { "type": "Number", "value": 1.0 } } },
+            "gt1": { "runtime_id": "gt1", "authoring_path": [],
+                "implementation": { "impl_id": "gt", "version": "0.1.0" },
+                "parameters": {} },
+            "emit": { "runtime_id": "emit", "authoring_path": [],
+                "implementation": { "impl_id": "emit_if_true", "version": "0.1.0" },
+                "parameters": {} },
+            "act": { "runtime_id": "act", "authoring_path": [],
+                "implementation": { "impl_id": "ack_action", "version": "0.1.0" },
+                "parameters": { "accept": { "type": "Bool", "value": true } } }
+        },
+        "edges": [
+            { "from": { "type": "NodePort", "node_id": "src_a", "port_name": "value" },
+              "to": { "type": "NodePort", "node_id": "gt1", "port_name": "a" } },
+            { "from": { "type": "NodePort", "node_id": "src_b", "port_name": "value" },
+              "to": { "type": "NodePort", "node_id": "gt1", "port_name": "b" } },
+            { "from": { "type": "NodePort", "node_id": "gt1", "port_name": "result" },
+              "to": { "type": "NodePort", "node_id": "emit", "port_name": "input" } },
+            { "from": { "type": "NodePort", "node_id": "emit", "port_name": "event" },
+              "to": { "type": "NodePort", "node_id": "act", "port_name": "event" } }
+        ],
+        "boundary_inputs": [],
+        "boundary_outputs": [
+            { "name": "action_outcome", "maps_to": { "node_id": "act", "port_name": "outcome" } }
+        ]
+    }"#;
+
+    fn body(response: &Response) -> serde_json::Value {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[test]
+    fn validate_accepts_hello_world() {
+        let backend = Backend::new().unwrap();
+        let response = backend.handle("POST", "/validate", HELLO_WORLD);
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            body(&response),
+            serde_json::json!({ "success": true, "errors": [] })
+        );
+    }
+
+    #[test]
+    fn run_returns_report_in_contract_shape() {
+        let backend = Backend::new().unwrap();
+        let response = backend.handle("POST", "/run", HELLO_WORLD);
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            body(&response),
+            serde_json::json!({
+                "validation": { "success": true, "errors": [] },
+                "execution": {
+                    "success": true,
+                    "report": {
+                        "outputs": {
+                            "action_outcome": {
+                                "type": "Event",
+                                "value": { "kind": "Action", "payload": "Filled" }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn run_reports_validation_failure_without_execution() {
+        let backend = Backend::new().unwrap();
+        let graph = HELLO_WORLD.replace(r#""port_name": "b" "#, r#""port_name": "c" "#);
+        let json = body(&backend.handle("POST", "/run", &graph));
+        assert_eq!(json["validation"]["success"], false);
+        assert_eq!(
+            json["validation"]["errors"],
+            serde_json::json!([
+                {
+                    "kind": "MissingRequiredInput",
+                    "message": "required input 'b' of node 'gt1' is not connected",
+                    "location": { "node_id": "gt1", "port_name": "b" }
+                },
+                {
+                    "kind": "InvalidWiring",
+                    "message": "node 'gt1' has no input named 'c'",
+                    "location": { "node_id": "gt1", "port_name": "c", "edge_index": 1 }
+                }
+            ])
+        );
+        assert!(json.get("execution").is_none());
+    }
+
+    #[test]
+    fn configuration_errors_are_not_reported_as_panics() {
+        let unknown = map_exec_error(&ExecError::UnknownPrimitive {
+            id: "gt".to_string(),
+            version: "9.9.9".to_string(),
+        });
+        assert_eq!(unknown.kind, ExecutionErrorKind::InvalidConfiguration);
+        assert_eq!(unknown.message, "primitive 'gt@9.9.9' is not registered");
+
+        let state = map_exec_error(&ExecError::UndeclaredState {
+            node: "gt1".to_string(),
+        });
+        assert_eq!(state.kind, ExecutionErrorKind::InvalidConfiguration);
+        assert_eq!(state.node_id.as_deref(), Some("gt1"));
+    }
+
+    #[test]
+    fn malformed_body_is_a_bad_request() {
+        let backend = Backend::new().unwrap();
+        assert_eq!(backend.handle("POST", "/validate", "{").status, 400);
+        assert_eq!(backend.handle("GET", "/run", "").status, 405);
+        assert_eq!(backend.handle("POST", "/nope", "").status, 404);
+    }
+}
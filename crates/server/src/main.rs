@@ -0,0 +1,70 @@
+//! `ergo-server` — serves `/validate` and `/run` for the authoring UI.
+//!
+//! Usage: `ergo-server [ADDR]` (default `127.0.0.1:3001`, the UI adapter's default base URL).
+
+use std::io::Read;
+
+use ergo_server::Backend;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+const DEFAULT_ADDR: &str = "127.0.0.1:3001";
+
+fn main() {
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDR.to_string());
+
+    let backend = match Backend::new() {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("ergo-server: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let server = match Server::http(&addr) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("ergo-server: cannot bind {}: {}", addr, e);
+            std::process::exit(1);
+        }
+    };
+    eprintln!("ergo-server listening on http://{}", addr);
+
+    for request in server.incoming_requests() {
+        serve(&backend, request);
+    }
+}
+
+fn serve(backend: &Backend, mut request: Request) {
+    // The UI dev server runs on a different origin, so every response carries CORS headers.
+    if *request.method() == Method::Options {
+        let _ = request.respond(with_cors(Response::from_string("").with_status_code(204)));
+        return;
+    }
+
+    let mut body = String::new();
+    let response = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => backend.handle(request.method().as_str(), request.url(), &body),
+        Err(e) => ergo_server::Response {
+            status: 400,
+            body: serde_json::json!({ "message": format!("unreadable body: {}", e) }).to_string(),
+        },
+    };
+
+    let http = Response::from_string(response.body)
+        .with_status_code(response.status)
+        .with_header(header("Content-Type", "application/json"));
+    let _ = request.respond(with_cors(http));
+}
+
+fn with_cors<R: Read>(response: Response<R>) -> Response<R> {
+    response
+        .with_header(header("Access-Control-Allow-Origin", "*"))
+        .with_header(header("Access-Control-Allow-Methods", "POST, OPTIONS"))
+        .with_header(header("Access-Control-Allow-Headers", "Content-Type"))
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("static header is valid")
+}
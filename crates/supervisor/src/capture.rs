@@ -57,6 +57,8 @@ impl<L: DecisionLog, R: RuntimeInvoker> CapturingSession<L, R> {
     }
 
     pub fn on_event(&mut self, event: ExternalEvent) {
+        // Stamp before capturing so the record holds the environment the episode reads.
+        let event = self.supervisor.runtime.intake(event);
         {
             let mut guard = self.bundle.lock().expect("capture bundle poisoned");
             guard.events.push(ExternalEventRecord::from_event(&event));
@@ -175,6 +175,7 @@ impl<L: DecisionLog, R: RuntimeInvoker> Supervisor<L, R> {
     }
 
     pub fn on_event(&mut self, event: ExternalEvent) {
+        let event = self.runtime.intake(event);
         self.clock.advance_to(event.at());
         let now = self.clock.now();
         let episode_id = self.next_episode_id();
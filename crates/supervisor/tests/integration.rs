@@ -12,6 +12,7 @@ use ergo_runtime::cluster::{
     ExpandedEdge, ExpandedEndpoint, ExpandedGraph, ExpandedNode, ImplementationInstance,
     OutputPortSpec, OutputRef, ParameterValue,
 };
+use ergo_runtime::common::Value;
 use ergo_supervisor::replay::replay;
 use ergo_supervisor::{
     CapturingSession, Constraints, Decision, DecisionLog, DecisionLogEntry, Supervisor,
@@ -278,3 +279,95 @@ fn capturing_session_enables_round_trip_replay() {
         "retry_count should round trip through replay"
     );
 }
+
+/// Latch: the first episode finds `armed` unset and sets it; later episodes see it set.
+#[test]
+fn capturing_session_records_the_environment_each_episode_reads() {
+    let node = |id: &str, impl_id: &str, parameters: Vec<(&str, ParameterValue)>| {
+        let node = ExpandedNode {
+            runtime_id: id.to_string(),
+            authoring_path: vec![],
+            implementation: ImplementationInstance {
+                impl_id: impl_id.to_string(),
+                version: "0.1.0".to_string(),
+            },
+            parameters: parameters
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        };
+        (id.to_string(), node)
+    };
+    let edge = |from: &str, output: &str, to: &str, input: &str| ExpandedEdge {
+        from: ExpandedEndpoint::NodePort {
+            node_id: from.to_string(),
+            port_name: output.to_string(),
+        },
+        to: ExpandedEndpoint::NodePort {
+            node_id: to.to_string(),
+            port_name: input.to_string(),
+        },
+    };
+    let graph = ExpandedGraph {
+        nodes: HashMap::from([
+            node(
+                "armed",
+                "state_read_bool",
+                vec![("key", ParameterValue::String("armed".to_string()))],
+            ),
+            node("unarmed", "not", vec![]),
+            node("arm", "emit_if_true", vec![]),
+            node(
+                "write",
+                "state_write",
+                vec![
+                    ("key", ParameterValue::String("armed".to_string())),
+                    ("value_type", ParameterValue::Enum("bool".to_string())),
+                    ("flag", ParameterValue::Bool(true)),
+                ],
+            ),
+        ]),
+        edges: vec![
+            edge("armed", "value", "unarmed", "value"),
+            edge("unarmed", "result", "arm", "input"),
+            edge("arm", "event", "write", "event"),
+        ],
+        boundary_inputs: Vec::new(),
+        boundary_outputs: Vec::new(),
+    };
+    let runtime = RuntimeHandle::new(
+        Arc::new(graph),
+        Arc::new(build_core_catalog()),
+        Arc::new(core_registries().expect("core registries should build")),
+    );
+    runtime.environment().set("unread", Value::Bool(true));
+
+    let mut session = CapturingSession::new(
+        GraphId::new("latch"),
+        Constraints::default(),
+        CapturingLog::new(),
+        runtime.clone(),
+    );
+    for id in ["e1", "e2", "e3"] {
+        session.on_event(ExternalEvent::mechanical(
+            EventId::new(id),
+            ExternalEventKind::Tick,
+        ));
+    }
+    let bundle = session.into_bundle();
+
+    let armed: Vec<Option<&Value>> = bundle
+        .events
+        .iter()
+        .map(|record| record.environment.get("armed"))
+        .collect();
+    assert_eq!(
+        armed,
+        vec![None, Some(&Value::Bool(true)), Some(&Value::Bool(true))]
+    );
+    assert!(bundle
+        .events
+        .iter()
+        .all(|record| record.validate_hash() && !record.environment.contains_key("unread")));
+    assert_eq!(runtime.environment().get("armed"), Some(Value::Bool(true)));
+}
@@ -88,7 +88,8 @@ export type RuntimeValue =
   | { type: 'Series'; value: number[] }
   | { type: 'Bool'; value: boolean }
   | { type: 'Event'; value: RuntimeEvent }
-  | { type: 'String'; value: string };
+  | { type: 'String'; value: string }
+  | { type: 'List'; value: RuntimeValue[] };
 
 export interface RuntimeEvent {
   kind: string;
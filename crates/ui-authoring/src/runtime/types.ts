@@ -19,7 +19,8 @@ export type ValidationErrorKind =
   | 'TypeMismatch'
   | 'InvalidWiring'
   | 'UngatedAction'
-  | 'CycleDetected';
+  | 'CycleDetected'
+  | 'InvalidParameter';
 
 export interface ValidationError {
   kind: ValidationErrorKind;
@@ -44,7 +45,9 @@ export type ExecutionErrorKind =
   | 'RuntimePanic'
   | 'TypeCoercionFailed'
   | 'MissingInput'
-  | 'ActionFailed';
+  | 'ActionFailed'
+  | 'PrimitiveFailed'
+  | 'InvalidConfiguration';
 
 export interface ExecutionError {
   kind: ExecutionErrorKind;